@@ -0,0 +1,22 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = poloto::plot("build times", "commit", "seconds");
+
+    let times = (0..40).map(|x| {
+        let x = x as f64;
+        let base = if x < 25.0 { 30.0 } else { 45.0 };
+        [x, base + 3.0 * (x * 1.3).sin()]
+    });
+
+    plotter.line("build time", times.twice_iter());
+
+    plotter.arrow([20.0, 41.0], [25.0, 45.0], "regression introduced here");
+    plotter.marker([25.0, 45.0]);
+    plotter.text([12.0, 38.0], "before");
+
+    plotter.render_io(std::io::stdout())?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use poloto::prelude::*;
-fn main() -> std::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let data = [[1.0f64, 4.0], [2.0, 5.0], [3.0, 6.0]];
 
     use poloto::build::{HeaderBuilder, PlotterBuilder, StyleBuilder, NUM_COLORS};
@@ -16,7 +16,7 @@ fn main() -> std::fmt::Result {
         )
         .build("cows per year", "year", "cows");
 
-    plotter.line("cow", data.iter().map(|&x| x).twice_iter());
+    plotter.line("cow", data.iter().copied().twice_iter());
 
     plotter.render_io(std::io::stdout())?;
 
@@ -1,5 +1,5 @@
 use poloto::prelude::*;
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let mut s = poloto::build::PlotterBuilder::new()
         .with_header(
             poloto::build::HeaderBuilder::new()
@@ -37,7 +37,7 @@ fn main() -> core::fmt::Result {
     Ok(())
 }
 
-const HEADER: &'static str = r###"
+const HEADER: &str = r###"
 <head>
 <script>
     var flip=false;
@@ -1,7 +1,7 @@
 use poloto::prelude::*;
 
 //PIPE me to a file!
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let mut s = poloto::build::PlotterBuilder::new()
         .with_header(
             poloto::build::HeaderBuilder::new()
@@ -3,7 +3,7 @@ use poloto::prelude::*;
 ///with the exception that the styles are not in the svg itself.
 ///The svg is embeded in the html, and we can modify its style by
 ///using styles that override the svg's style.
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let mut s = poloto::plot("Demo: you can use CSS patterns if you embed SVG!", "x", "y");
 
     let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
@@ -45,7 +45,7 @@ fn main() -> core::fmt::Result {
     Ok(())
 }
 
-const HEADER: &'static str = r###"
+const HEADER: &str = r###"
 <header>
 <script>
     var flip=true;
@@ -0,0 +1,28 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    let mut figure = poloto::Figure::new("Demo: Shared Axes", 2);
+    figure.share_x().share_y();
+
+    for (i, &offset) in [0.0, 0.5, 1.0, 1.5].iter().enumerate() {
+        let mut plotter = poloto::plot(poloto::move_format!("panel {}", i), "x", "y");
+        plotter.line(
+            "sin",
+            x.clone().map(move |x| [x, (x + offset).sin()]).twice_iter(),
+        );
+        plotter.line(
+            "cos",
+            x.clone()
+                .map(move |x| [x, (x + offset).cos() * 2.0])
+                .twice_iter(),
+        );
+        figure.add(plotter);
+    }
+
+    figure.render_io(std::io::stdout())?;
+
+    Ok(())
+}
@@ -1,21 +1,15 @@
 use poloto::iter::*;
 //PIPE me to a file!
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let mut s = poloto::plot("Demo: Some Trigonometry Plots", "x", "y");
 
     let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
 
-    let temp_file = "temp.txt";
-
-    //Use a temporary file to store the plots
-    s.line(
-        "cos",
-        file_buffer(x.clone().map(|x| [x, x.cos()]), temp_file),
-    );
+    //Use a temporary file to store the plots.
+    //It is removed once rendering is done.
+    s.line("cos", file_buffer(x.clone().map(|x| [x, x.cos()]))?);
 
     s.render_io(std::io::stdout())?;
 
-    std::fs::remove_file(temp_file).unwrap();
-
     Ok(())
 }
@@ -1,6 +1,6 @@
 use poloto::build::*;
 use poloto::prelude::*;
-fn main() -> std::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let s = StyleBuilder::new()
         .with_text_color("white")
         .with_back_color("black")
@@ -0,0 +1,15 @@
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = poloto::plot("Demo: Sampled Functions", "x", "y");
+
+    //Sampled more densely near zero, where the function oscillates quickly.
+    plotter.function("sin(1/x)", 0.02..=1.0, |x| (1.0 / x).sin());
+
+    plotter.parametric("lissajous", 0.0..=std::f64::consts::TAU, |t| {
+        [0.5 + 0.4 * (3.0 * t).sin(), 0.8 * (2.0 * t).cos()]
+    });
+
+    plotter.render_io(std::io::stdout())?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use poloto::prelude::*;
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let mut s = poloto::plot("Demo: Hovering and shadows", "x", "y");
 
     let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
@@ -30,7 +30,7 @@ fn main() -> core::fmt::Result {
     Ok(())
 }
 
-const HEADER: &'static str = r###"
+const HEADER: &str = r###"
 <head>
 <svg width=0 height=0>
 <defs>
@@ -0,0 +1,24 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = poloto::plot("Demo: zoom, pan and toggle plots", "x", "y");
+
+    let x = (0..500).map(|x| (x as f64 / 500.0) * 10.0);
+
+    plotter
+        .line("cos", x.clone().map(|x| [x, x.cos()]).twice_iter())
+        .tooltips();
+    plotter
+        .scatter(
+            "sin",
+            x.clone().step_by(10).map(|x| [x, x.sin()]).twice_iter(),
+        )
+        .tooltips();
+    plotter.hline(0.0, "", false);
+
+    let mut s = String::new();
+    plotter.render_html(&mut s)?;
+    println!("{}", s);
+    Ok(())
+}
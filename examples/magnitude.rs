@@ -1,11 +1,11 @@
 use poloto::prelude::*;
-fn main() -> core::fmt::Result {
-    let mut s = poloto::plot("cows per year", "year", "cow");
-
+fn main() -> Result<(), poloto::Error> {
     // TEST 3
     let data = [[0.000001, 0.000001], [0.000001000000001, 0.000001000000001]];
 
-    s.scatter("", data.iter().map(|x| *x).twice_iter());
+    let mut s = poloto::plot("cows per year", "year", "cow");
+
+    s.scatter("", data.iter().copied().twice_iter());
 
     s.render_io(std::io::stdout())?;
 
@@ -0,0 +1,17 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+//Needs the png feature: cargo run --example png --features png
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = poloto::plot("Some Trigonometry Plots 🥳", "x", "y");
+
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    plotter.line("cos", x.clone().map(|x| [x, x.cos()]).twice_iter());
+    plotter.scatter("sin", x.clone().map(|x| [x, x.sin()]).twice_iter());
+
+    //Twice as many pixels in each direction as svg units.
+    plotter.render_png(std::io::stdout(), 192.0)?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let days = ["mon", "tue", "wed", "thu", "fri", "sat"];
+    let sold = [12, 17, 9, 14, 20, 25];
+
+    let mut plotter = poloto::plot("fruit sold", "day", "amount");
+
+    //The last point is the right edge of the last bar.
+    plotter
+        .histogram(
+            "apples",
+            (0..)
+                .zip(sold.iter().chain(core::iter::once(&0)))
+                .map(|(x, &y)| [x, y])
+                .twice_iter(),
+        )
+        .point_labels_from(days.iter());
+
+    plotter
+        .scatter(
+            "oranges",
+            (0..6)
+                .map(|x| [x as f64 + 0.5, 5.0 + x as f64 * 1.5])
+                .twice_iter(),
+        )
+        .point_labels(|[_, y]| y);
+
+    plotter.render_io(std::io::stdout())?;
+
+    Ok(())
+}
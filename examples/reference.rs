@@ -0,0 +1,22 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = poloto::plot("request latency", "hour", "milliseconds");
+
+    let latency = (0..48).map(|x| {
+        let x = x as f64;
+        let spike = if (20.0..26.0).contains(&x) { 60.0 } else { 0.0 };
+        [x, 80.0 + 15.0 * (x / 4.0).sin() + spike]
+    });
+
+    plotter.xspan(20.0..=25.0, "incident", false);
+    plotter.hline(180.0, "slo", true);
+    plotter.vline(40.0, "deploy", false);
+
+    plotter.line("p99", latency.twice_iter());
+
+    plotter.render_io(std::io::stdout())?;
+
+    Ok(())
+}
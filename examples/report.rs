@@ -0,0 +1,40 @@
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let sizes = (1..50).map(|x| x as f64 * 1000.0);
+
+    let mut insert = poloto::plot("insert", "elements", "nanoseconds");
+    insert.line(
+        "vec",
+        sizes.clone().map(|x| [x, 20.0 + x * 0.01]).twice_iter(),
+    );
+    insert.line(
+        "btree",
+        sizes
+            .clone()
+            .map(|x| [x, 40.0 + x.ln() * 10.0])
+            .twice_iter(),
+    );
+
+    let mut lookup = poloto::plot("lookup", "elements", "nanoseconds");
+    lookup.scatter("vec", sizes.clone().map(|x| [x, 5.0 + x.ln()]).twice_iter());
+    lookup.scatter(
+        "btree",
+        sizes.clone().map(|x| [x, 10.0 + x.ln() * 3.0]).twice_iter(),
+    );
+
+    let mut report = poloto::Report::new("Collection benchmarks");
+    report
+        .paragraph("Each benchmark was run on collections of 1000 to 49000 elements.")
+        .heading("Insertion")
+        .paragraph("Inserting into the middle of a vec gets slower as it grows.")
+        .plot(insert)
+        .heading("Lookup")
+        .paragraph("Both collections use a binary search, so lookups stay fast.")
+        .plot(lookup);
+
+    report.render_io(std::io::stdout())?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use poloto::build::*;
+use poloto::prelude::*;
+
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = PlotterBuilder::new()
+        .with_header(HeaderBuilder::new().push_css_default().build())
+        .with_secondary_yname("latency (ms)")
+        .build("throughput vs latency", "time", "requests per second");
+
+    let x = (0..50).map(|x| x as f64);
+
+    plotter.line(
+        "throughput",
+        x.clone()
+            .map(|x| [x, 10000.0 + 2000.0 * (x / 5.0).sin()])
+            .twice_iter(),
+    );
+
+    //Plots added from now on are scaled against the right y axis.
+    plotter.y_axis(poloto::YAxis::Secondary);
+
+    plotter.scatter(
+        "latency",
+        x.clone().map(|x| [x, 2.0 + (x / 5.0).cos()]).twice_iter(),
+    );
+
+    plotter.render_io(std::io::stdout())?;
+
+    Ok(())
+}
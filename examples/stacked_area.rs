@@ -0,0 +1,23 @@
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut s = poloto::plot("memory use by subsystem", "time", "MB");
+
+    let x = (0..50).map(|x| x as f64);
+
+    s.stacked_area(
+        poloto::StackedArea::new()
+            .layer(
+                "cache",
+                x.clone().map(|x| [x, 100.0 + 20.0 * (x / 5.0).sin()]),
+            )
+            .layer("heap", x.clone().map(|x| [x, 50.0 + x]))
+            .layer(
+                "stack",
+                x.clone().map(|x| [x, 20.0 + 5.0 * (x / 3.0).cos()]),
+            ),
+    );
+
+    s.render_io(std::io::stdout())?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use poloto::prelude::*;
+
+//Run me in a terminal!
+fn main() -> Result<(), poloto::Error> {
+    let mut plotter = poloto::plot("bench results", "iteration", "time (ms)");
+
+    let x = (0..50).map(|x| x as f64);
+
+    plotter.line(
+        "warm",
+        x.clone()
+            .map(|x| [x, 20.0 + 5.0 * (x / 5.0).sin()])
+            .twice_iter(),
+    );
+
+    plotter.scatter(
+        "cold",
+        x.clone()
+            .step_by(2)
+            .map(|x| [x, 30.0 + 4.0 * (x / 7.0).cos()])
+            .twice_iter(),
+    );
+
+    plotter.histogram(
+        "gc",
+        x.clone()
+            .step_by(5)
+            .map(|x| [x, 2.0 + (x / 10.0)])
+            .twice_iter(),
+    );
+
+    //Color the plots with the ansi color codes of their index.
+    let text = plotter.render_terminal(String::new(), [80, 24], true)?;
+    print!("{}", text);
+
+    Ok(())
+}
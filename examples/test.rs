@@ -18,51 +18,20 @@ const fn generate_test() -> [&'static [[f64; 2]]; 8] {
     [test0, test1, test2, test3, test4, test5, test6, test7]
 }
 
-use core::fmt;
 use poloto::prelude::*;
-use tagger::prelude::*;
 
 //Create a bunch of graphs with different scales to try to expose corner cases.
-fn main() -> fmt::Result {
-    let mut root = tagger::Element::new(tagger::upgrade(std::io::stdout()));
+//PIPE me to a file!
+fn main() -> Result<(), poloto::Error> {
+    let mut figure = poloto::Figure::new("Corner cases", 4);
 
-    root.elem("html", |writer| {
-        let html = writer.write(|w| Ok(w))?;
+    for (i, test) in generate_test().iter().enumerate() {
+        let mut s = poloto::plot(poloto::move_format!("test {}", i), "x", "y");
+        s.scatter("test", test.iter().copied().twice_iter());
+        figure.add(s);
+    }
 
-        html.elem("div", |writer| {
-            let div = writer.write(|w| w.attr("style", "display:flex;flex-wrap:wrap;"))?;
+    figure.render_io(std::io::stdout())?;
 
-            for (i, test) in generate_test().iter().enumerate() {
-                div.elem("svg", |writer| {
-                    //Build the svg tag from scratch so we can use our own
-                    //width and height
-                    let svg = writer.write(|w| {
-                        use poloto::build::default_tags::*;
-                        w.attr("class", CLASS)?;
-                        w.attr("xmlns", XMLNS)?;
-                        w.with_attr("viewBox", wr!("0 0 {} {}", WIDTH, HEIGHT))?;
-                        w.attr("width", "500px")?.attr("height", "100%")?;
-                        Ok(w)
-                    })?;
-
-                    let mut s = poloto::build::PlotterBuilder::new()
-                        .with_svg(false)
-                        .with_header(
-                            poloto::build::HeaderBuilder::new()
-                                .push_css_default()
-                                .build(),
-                        )
-                        .build(move_format!("test {}", i), "x", "y");
-
-                    s.scatter("test", test.iter().copied().twice_iter());
-
-                    s.render(svg)
-                })?;
-            }
-            Ok(div)
-        })?;
-
-        Ok(html)
-    })?;
     Ok(())
 }
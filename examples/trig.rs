@@ -2,7 +2,7 @@ use poloto::build::*;
 use poloto::prelude::*;
 
 //PIPE me to a file!
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let header = HeaderBuilder::new()
         .push(
             StyleBuilder::new()
@@ -1,5 +1,5 @@
 use poloto::prelude::*;
-fn main() -> core::fmt::Result {
+fn main() -> Result<(), poloto::Error> {
     let mut s = poloto::plot("test", "x", "y");
 
     let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
@@ -17,7 +17,7 @@ fn main() -> core::fmt::Result {
     s.line_fill("sin-20", x.clone().map(|x| [x, x.sin() - 20.]).twice_iter());
 
     //Write the graph to a file
-    let file = std::fs::File::create("assets/write_to_file.svg").unwrap();
+    let file = std::fs::File::create("assets/write_to_file.svg")?;
 
     s.render_io(file)?;
 
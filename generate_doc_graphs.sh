@@ -7,9 +7,19 @@ cargo run --example hover_shadow_html > assets/hover_shadow.html &&
 cargo run --example magnitude > assets/magnitude.svg &&
 cargo run --example trig > assets/trig.svg &&
 cargo run --example simple > assets/simple.svg &&
-cargo run --example test > assets/test.html
+cargo run --example test > assets/test.svg
 cargo run --example write_to_file
 cargo run --example file_iter > assets/file_iter.svg
 cargo run --example from_scratch > assets/from_scratch.svg
 
 
+cargo run --example secondary_axis > assets/secondary_axis.svg
+cargo run --example stacked_area > assets/stacked_area.svg
+cargo run --example function > assets/function.svg
+cargo run --example figure > assets/figure.svg
+cargo run --example annotation > assets/annotation.svg
+cargo run --example reference > assets/reference.svg
+cargo run --example point_labels > assets/point_labels.svg
+cargo run --example interactive_html > assets/interactive.html
+cargo run --example report > assets/report.html
+cargo run --example png --features png > assets/png.png
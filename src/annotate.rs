@@ -0,0 +1,81 @@
+//Annotations added with Plotter::text, Plotter::arrow, Plotter::marker,
+//and the reference lines and spans added with Plotter::hline, Plotter::vline,
+//Plotter::xspan and Plotter::yspan.
+use super::*;
+
+//An annotation at data coordinates, scaled against the y axis it was added to.
+pub(crate) struct Annotation<'a> {
+    pub kind: AnnotationKind<'a>,
+    pub axis: YAxis,
+    pub clip: bool,
+}
+
+pub(crate) enum AnnotationKind<'a> {
+    //Text centered on a point.
+    Text {
+        pos: [f64; 2],
+        text: Box<dyn Display + 'a>,
+    },
+    //Text centered on a point, with an arrow from it to another point.
+    Arrow {
+        from: [f64; 2],
+        to: [f64; 2],
+        text: Box<dyn Display + 'a>,
+    },
+    //A ring around a point.
+    Marker {
+        pos: [f64; 2],
+    },
+    //A line across the plot area at a value of the x axis if vertical,
+    //otherwise at a value of the y axis.
+    Rule {
+        vertical: bool,
+        value: f64,
+        label: Box<dyn Display + 'a>,
+        include_in_bounds: bool,
+    },
+    //A shaded band across the plot area between two values of the x axis if vertical,
+    //otherwise between two values of the y axis.
+    Span {
+        vertical: bool,
+        range: [f64; 2],
+        label: Box<dyn Display + 'a>,
+        include_in_bounds: bool,
+    },
+}
+
+impl<'a> Annotation<'a> {
+    //Spans are drawn behind the plots. Everything else is drawn on top.
+    pub fn is_background(&self) -> bool {
+        matches!(self.kind, AnnotationKind::Span { .. })
+    }
+
+    //The values that must be visible, as `[minx,maxx,miny,maxy]` with
+    //infinities for the axis that isn't constrained.
+    pub fn bounds(&self) -> Option<[f64; 4]> {
+        let (vertical, [min, max]) = match self.kind {
+            AnnotationKind::Rule {
+                vertical,
+                value,
+                include_in_bounds: true,
+                ..
+            } => (vertical, [value, value]),
+            AnnotationKind::Span {
+                vertical,
+                range,
+                include_in_bounds: true,
+                ..
+            } => (vertical, range),
+            _ => return None,
+        };
+        if !(min.is_finite() && max.is_finite()) {
+            return None;
+        }
+        let (min, max) = (min.min(max), min.max(max));
+        Some(if vertical {
+            [min, max, f64::INFINITY, f64::NEG_INFINITY]
+        } else {
+            [f64::INFINITY, f64::NEG_INFINITY, min, max]
+        })
+    }
+}
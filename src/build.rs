@@ -6,14 +6,20 @@ use super::*;
 ///The number of unique colors.
 pub const NUM_COLORS: usize = 8;
 
+//The id of the next plotter that is built.
+static NEXT_ID: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
 ///Used internally to implement [`Names`]
-pub struct NamesStruct<A, B, C, D> {
+pub struct NamesStruct<A, B, C, D, E> {
     title: A,
     xname: B,
     yname: C,
     header: D,
+    yname2: E,
 }
-impl<A: Display, B: Display, C: Display, D: Display> Names for NamesStruct<A, B, C, D> {
+impl<A: Display, B: Display, C: Display, D: Display, E: Display> Names
+    for NamesStruct<A, B, C, D, E>
+{
     fn write_header(&self, fm: &mut fmt::Formatter) -> fmt::Result {
         self.header.fmt(fm)
     }
@@ -26,6 +32,9 @@ impl<A: Display, B: Display, C: Display, D: Display> Names for NamesStruct<A, B,
     fn write_yname(&self, fm: &mut fmt::Formatter) -> fmt::Result {
         self.yname.fmt(fm)
     }
+    fn write_yname2(&self, fm: &mut fmt::Formatter) -> fmt::Result {
+        self.yname2.fmt(fm)
+    }
 }
 
 ///Used internally to write out the header/title/xname/yname.
@@ -34,6 +43,10 @@ pub trait Names {
     fn write_title(&self, fm: &mut fmt::Formatter) -> fmt::Result;
     fn write_xname(&self, fm: &mut fmt::Formatter) -> fmt::Result;
     fn write_yname(&self, fm: &mut fmt::Formatter) -> fmt::Result;
+    ///Write the label of the secondary y axis. Writes nothing by default.
+    fn write_yname2(&self, _fm: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
 }
 
 ///Contains building blocks for create the default svg an styling tags from scratch.
@@ -131,6 +144,7 @@ impl<A: Display, B: Display, C: Display> StyleBuilder<A, B, C> {
                     .poloto_text{{fill: var(--poloto_fg_color,{0});  }}
                     .poloto_axis_lines{{stroke: var(--poloto_fg_color,{0});stoke-width:3;fill:none}}
                     .poloto_background{{fill: var(--poloto_bg_color,{1}); }}
+                    .poloto_annotation{{stroke: var(--poloto_fg_color,{0});fill: var(--poloto_fg_color,{0}); }}
                     .poloto0stroke{{stroke:  var(--poloto_color0,{2}); }}
                     .poloto1stroke{{stroke:  var(--poloto_color1,{3}); }}
                     .poloto2stroke{{stroke:  var(--poloto_color2,{4}); }}
@@ -176,6 +190,7 @@ impl<A: Display, B: Display, C: Display> StyleBuilder<A, B, C> {
                 .poloto_text{{fill: {0};  }}
                 .poloto_axis_lines{{stroke: {0};stoke-width:3;fill:none}}
                 .poloto_background{{fill: {1}; }}
+                .poloto_annotation{{stroke: {0};fill: {0}; }}
                 .poloto0stroke{{stroke:  {2}; }}
                 .poloto1stroke{{stroke:  {3}; }}
                 .poloto2stroke{{stroke:  {4}; }}
@@ -283,8 +298,9 @@ impl<D: Display> HeaderBuilder<D> {
 }
 
 ///If [`plot`] isn't good enough, use this struct for more control.
-pub struct PlotterBuilder<D: fmt::Display> {
+pub struct PlotterBuilder<D: fmt::Display, E: fmt::Display = &'static str> {
     header: D,
+    yname2: E,
     svgtag: bool,
 }
 impl Default for PlotterBuilder<&'static str> {
@@ -296,12 +312,25 @@ impl PlotterBuilder<&'static str> {
     pub fn new() -> Self {
         PlotterBuilder {
             header: "",
+            yname2: "",
             svgtag: true,
         }
     }
-    pub fn with_header<J: Display>(self, header: J) -> PlotterBuilder<J> {
+}
+impl<D: Display, E: Display> PlotterBuilder<D, E> {
+    pub fn with_header<J: Display>(self, header: J) -> PlotterBuilder<J, E> {
         PlotterBuilder {
             header,
+            yname2: self.yname2,
+            svgtag: self.svgtag,
+        }
+    }
+    ///Set the label of the secondary y axis.
+    ///It is only drawn if some plots are assigned to [`YAxis::Secondary`].
+    pub fn with_secondary_yname<J: Display>(self, yname2: J) -> PlotterBuilder<D, J> {
+        PlotterBuilder {
+            header: self.header,
+            yname2,
             svgtag: self.svgtag,
         }
     }
@@ -311,13 +340,13 @@ impl PlotterBuilder<&'static str> {
     }
 }
 
-impl<'a, D: Display + 'a> PlotterBuilder<D> {
+impl<'a, D: Display + 'a, E: Display + 'a> PlotterBuilder<D, E> {
     pub fn build<A: Display + 'a, B: Display + 'a, C: Display + 'a>(
         self,
         title: A,
         xname: B,
         yname: C,
-    ) -> Plotter<'a, NamesStruct<A, B, C, D>> {
+    ) -> Plotter<'a, NamesStruct<A, B, C, D, E>> {
         let svgtag = if self.svgtag {
             SvgTagOption::Svg
         } else {
@@ -330,9 +359,20 @@ impl<'a, D: Display + 'a> PlotterBuilder<D> {
                 xname,
                 yname,
                 header: self.header,
+                yname2: self.yname2,
             },
             plots: Vec::new(),
             svgtag,
+            axis: YAxis::Primary,
+            baseline: Baseline::Bottom,
+            include_baseline: false,
+            decimation: Decimation::None,
+            clip: true,
+            options: RenderOptions {
+                id: NEXT_ID.fetch_add(1, core::sync::atomic::Ordering::Relaxed),
+                ..RenderOptions::default()
+            },
+            annotations: Vec::new(),
         }
     }
 }
@@ -0,0 +1,202 @@
+///How to reduce the number of points of a line before it is drawn.
+///
+///Points are reduced in svg coordinates, after they have been scaled,
+///so the result depends on how big the line ends up on the graph.
+///Each run of finite points is reduced separately, and keeps its first and last point.
+///To do this, each run is buffered in memory while it is being drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Decimation {
+    ///Draw every point.
+    None,
+    ///Largest-Triangle-Three-Buckets. Keep about this many points,
+    ///picking the ones that best preserve the shape of the line.
+    Lttb(usize),
+    ///Split the line into columns along the x axis and keep the smallest and biggest
+    ///point of each column, so spikes are never lost. Keep about this many points.
+    MinMax(usize),
+    ///Ramer–Douglas–Peucker. Drop points that are closer than this many pixels
+    ///to the simplified line.
+    Rdp(f64),
+}
+
+impl Decimation {
+    fn apply(self, points: Vec<[f64; 2]>) -> Vec<[f64; 2]> {
+        match self {
+            Decimation::None => points,
+            Decimation::Lttb(n) => lttb(&points, n),
+            Decimation::MinMax(n) => min_max(&points, n),
+            Decimation::Rdp(epsilon) => rdp(&points, epsilon),
+        }
+    }
+}
+
+//Decimate each run of finite points. Points that are not finite are passed through.
+pub(crate) fn decimate<'a>(
+    it: impl Iterator<Item = [f64; 2]> + 'a,
+    decimation: Decimation,
+) -> Box<dyn Iterator<Item = [f64; 2]> + 'a> {
+    if decimation == Decimation::None {
+        return Box::new(it);
+    }
+
+    let is_finite = |[x, y]: &[f64; 2]| x.is_finite() && y.is_finite();
+    let mut it = it.peekable();
+    let mut run = Vec::new().into_iter();
+    Box::new(core::iter::from_fn(move || {
+        if let Some(a) = run.next() {
+            return Some(a);
+        }
+        let a = it.next()?;
+        if !is_finite(&a) {
+            return Some(a);
+        }
+        let mut points = vec![a];
+        while let Some(b) = it.next_if(is_finite) {
+            points.push(b);
+        }
+        run = decimation.apply(points).into_iter();
+        run.next()
+    }))
+}
+
+fn lttb(points: &[[f64; 2]], n: usize) -> Vec<[f64; 2]> {
+    let len = points.len();
+    if n >= len || n < 3 {
+        return points.to_vec();
+    }
+
+    //The first and last point are kept. The rest are split into n-2 buckets.
+    let every = (len - 2) as f64 / (n - 2) as f64;
+    let bucket = |i: usize| ((i as f64 * every) as usize + 1).min(len - 1);
+
+    let mut out = Vec::with_capacity(n);
+    out.push(points[0]);
+    let mut a = points[0];
+    for i in 0..n - 2 {
+        //The average of the next bucket. The last bucket uses the last point.
+        let next = &points[bucket(i + 1)..bucket(i + 2).max(bucket(i + 1) + 1)];
+        let avg = next
+            .iter()
+            .fold([0.0, 0.0], |[ax, ay], [x, y]| [ax + x, ay + y]);
+        let avg = [avg[0] / next.len() as f64, avg[1] / next.len() as f64];
+
+        //Keep the point that makes the biggest triangle.
+        let area =
+            |[x, y]: [f64; 2]| ((a[0] - avg[0]) * (y - a[1]) - (a[0] - x) * (avg[1] - a[1])).abs();
+        let mut best = points[bucket(i)];
+        for &p in &points[bucket(i)..bucket(i + 1)] {
+            if area(p) > area(best) {
+                best = p;
+            }
+        }
+        out.push(best);
+        a = best;
+    }
+    out.push(points[len - 1]);
+    out
+}
+
+fn min_max(points: &[[f64; 2]], n: usize) -> Vec<[f64; 2]> {
+    let len = points.len();
+    if n >= len || n < 2 {
+        return points.to_vec();
+    }
+
+    let columns = n / 2;
+    let first = points[0][0];
+    let span = points[len - 1][0] - first;
+    let column = |x: f64| {
+        if span == 0.0 {
+            0
+        } else {
+            (((x - first) / span * columns as f64).max(0.0) as usize).min(columns - 1)
+        }
+    };
+
+    let mut out = Vec::with_capacity(n + 2);
+    out.push(points[0]);
+
+    //Consecutive points in the same column are reduced to their lowest and highest point,
+    //in the order they appear.
+    let mut start = 1;
+    while start < len - 1 {
+        let c = column(points[start][0]);
+        let mut end = start + 1;
+        while end < len - 1 && column(points[end][0]) == c {
+            end += 1;
+        }
+
+        let group = &points[start..end];
+        let (mut lo, mut hi) = (0, 0);
+        for (i, p) in group.iter().enumerate() {
+            if p[1] < group[lo][1] {
+                lo = i;
+            }
+            if p[1] > group[hi][1] {
+                hi = i;
+            }
+        }
+        let (first, second) = if lo < hi { (lo, hi) } else { (hi, lo) };
+        out.push(group[first]);
+        if second != first {
+            out.push(group[second]);
+        }
+
+        start = end;
+    }
+
+    out.push(points[len - 1]);
+    out
+}
+
+fn rdp(points: &[[f64; 2]], epsilon: f64) -> Vec<[f64; 2]> {
+    let len = points.len();
+    if len < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; len];
+    keep[0] = true;
+    keep[len - 1] = true;
+
+    //Ranges of points still to be simplified. A stack is used instead of
+    //recursion so that long lines can't overflow the call stack.
+    let mut stack = vec![(0, len - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let [ax, ay] = points[start];
+        let [bx, by] = points[end];
+        let [dx, dy] = [bx - ax, by - ay];
+        let length = dx.hypot(dy);
+
+        let distance = |[x, y]: [f64; 2]| {
+            if length == 0.0 {
+                (x - ax).hypot(y - ay)
+            } else {
+                (dy * x - dx * y + bx * ay - by * ax).abs() / length
+            }
+        };
+
+        let mut furthest = None;
+        let mut max = epsilon;
+        for (i, &p) in points.iter().enumerate().take(end).skip(start + 1) {
+            let d = distance(p);
+            if d > max {
+                max = d;
+                furthest = Some(i);
+            }
+        }
+
+        if let Some(i) = furthest {
+            keep[i] = true;
+            stack.push((start, i));
+            stack.push((i, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(&p, _)| p)
+        .collect()
+}
@@ -0,0 +1,97 @@
+use core::fmt;
+
+///The error type returned when rendering a plot.
+#[derive(Debug)]
+pub enum Error {
+    ///Writing to the formatter failed.
+    ///This can also be caused by a user supplied [`Display`](fmt::Display) implementation.
+    Fmt(fmt::Error),
+    ///Writing to a [`std::io::Write`] or to a file buffer failed.
+    Io(std::io::Error),
+    ///The data read back from a [`FileBuffer`](crate::iter::FileBuffer) was malformed.
+    FileBufferParse(String),
+    ///The plot data can't be plotted.
+    InvalidData(&'static str),
+    ///An error returned by a [`TryDoubleIterator`](crate::iter::TryDoubleIterator).
+    Iter(Box<dyn std::error::Error + Send + Sync>),
+    ///Rasterizing the svg failed, like when the image would be too big.
+    ///Only returned by `Plotter::render_png`.
+    Raster(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    //Errors that are already one of ours are not wrapped again.
+    pub(crate) fn from_iter_error(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+        let e = e.into();
+        let e = match e.downcast::<Error>() {
+            Ok(e) => return *e,
+            Err(e) => e,
+        };
+        match e.downcast::<std::io::Error>() {
+            Ok(e) => Error::Io(*e),
+            Err(e) => Error::Iter(e),
+        }
+    }
+}
+
+//Tagger closures can only return a fmt::Error. Run one, and return the real error
+//that stopped it in place of the fmt::Error.
+pub(crate) fn capture<T>(
+    func: impl FnOnce(&mut Capture) -> Result<T, fmt::Error>,
+) -> Result<T, Error> {
+    let mut capture = Capture(None);
+    let res = func(&mut capture);
+    match capture.0 {
+        Some(e) => Err(e),
+        None => Ok(res?),
+    }
+}
+
+pub(crate) struct Capture(Option<Error>);
+
+impl Capture {
+    //Hold on to the error, and return a fmt::Error to stop the closure.
+    pub(crate) fn hold<T>(&mut self, res: Result<T, Error>) -> Result<T, fmt::Error> {
+        res.map_err(|e| {
+            self.0 = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Fmt(_) => write!(f, "formatter error"),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::FileBufferParse(reason) => write!(f, "corrupt file buffer: {}", reason),
+            Error::InvalidData(reason) => write!(f, "invalid data: {}", reason),
+            Error::Iter(e) => write!(f, "plot iterator error: {}", e),
+            Error::Raster(e) => write!(f, "rasterize error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Fmt(e) => Some(e),
+            Error::Io(e) => Some(e),
+            Error::Iter(e) => Some(e.as_ref()),
+            Error::Raster(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(e: fmt::Error) -> Self {
+        Error::Fmt(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
@@ -0,0 +1,243 @@
+use super::*;
+use build::default_tags::*;
+use tagger::prelude::*;
+
+//The height of the row the figure title is drawn in.
+const TITLE_HEIGHT: f64 = 80.0;
+
+//A plotter with its names erased, so plotters with different names can share a figure or report.
+pub(crate) trait Panel {
+    fn check_not_consumed(&self) -> Result<(), Error>;
+    fn bounds(&mut self) -> Result<render::Bounds, Error>;
+    fn draw(
+        &mut self,
+        writer: &mut dyn fmt::Write,
+        bounds: render::Bounds,
+        edges: render::Edges,
+    ) -> Result<(), Error>;
+}
+
+impl<'a, D: Names> Panel for Plotter<'a, D> {
+    fn check_not_consumed(&self) -> Result<(), Error> {
+        Plotter::check_not_consumed(self)
+    }
+    fn bounds(&mut self) -> Result<render::Bounds, Error> {
+        render::find_bounds(&mut self.plots, &self.annotations)
+    }
+    fn draw(
+        &mut self,
+        mut writer: &mut dyn fmt::Write,
+        bounds: render::Bounds,
+        edges: render::Edges,
+    ) -> Result<(), Error> {
+        render::draw(
+            &mut writer,
+            &mut self.plots,
+            &self.annotations,
+            &self.names,
+            self.options,
+            bounds,
+            edges,
+        )?;
+        Ok(())
+    }
+}
+
+///Several [`Plotter`]s arranged in rows and columns inside one svg.
+///
+///Only the header of the figure is written, so every panel is styled the same way,
+///and the nth plot of every panel gets the same color.
+///The header, svg tag and plot area size of the panels themselves are ignored.
+///
+/// # Example
+///
+/// ```
+/// let data=[
+///         [1.0f64,4.0],
+///         [2.0,5.0],
+///         [3.0,6.0]
+/// ];
+/// use poloto::prelude::*;
+/// let mut a = poloto::plot("a","x","y");
+/// a.line("data",data.iter().twice_iter());
+/// let mut b = poloto::plot("b","x","y");
+/// b.scatter("data",data.iter().twice_iter());
+///
+/// let mut figure = poloto::Figure::new("figure",2);
+/// figure.share_y();
+/// figure.add(a).add(b);
+/// let svg = figure.render_to_string().unwrap();
+/// ```
+pub struct Figure<'a> {
+    title: Box<dyn Display + 'a>,
+    header: Box<dyn Display + 'a>,
+    columns: usize,
+    share_x: bool,
+    share_y: bool,
+    panels: Vec<Box<dyn Panel + 'a>>,
+}
+
+impl<'a> Figure<'a> {
+    ///Create a figure with the default css, whose panels are placed in rows of `columns` panels.
+    pub fn new(title: impl Display + 'a, columns: usize) -> Self {
+        Figure {
+            title: Box::new(title),
+            header: Box::new(build::HeaderBuilder::new().push_css_default().build()),
+            columns: columns.max(1),
+            share_x: false,
+            share_y: false,
+            panels: Vec::new(),
+        }
+    }
+
+    ///Replace the default css with a custom header. See [`build::HeaderBuilder`].
+    pub fn header(&mut self, header: impl Display + 'a) -> &mut Self {
+        self.header = Box::new(header);
+        self
+    }
+
+    ///Give every panel the same x axis range.
+    ///The x tick labels are only drawn on the bottom panel of each column.
+    pub fn share_x(&mut self) -> &mut Self {
+        self.share_x = true;
+        self
+    }
+
+    ///Give every panel the same primary y axis range.
+    ///The primary y tick labels are only drawn on the left panel of each row.
+    pub fn share_y(&mut self) -> &mut Self {
+        self.share_y = true;
+        self
+    }
+
+    ///Add a panel after the existing ones.
+    ///Panels fill up each row from left to right.
+    pub fn add<D: Names + 'a>(&mut self, plotter: Plotter<'a, D>) -> &mut Self {
+        self.panels.push(Box::new(plotter));
+        self
+    }
+
+    ///Render the svg to a [`String`].
+    pub fn render_to_string(&mut self) -> Result<String, Error> {
+        let mut s = String::new();
+        self.render(&mut s)?;
+        Ok(s)
+    }
+
+    ///Render the svg to a [`std::io::Write`], like [`Plotter::render_io`].
+    pub fn render_io<T: std::io::Write>(&mut self, writer: T) -> Result<T, Error> {
+        render_io(writer, |w| self.render(w).map(|_| ()))
+    }
+
+    ///Render the svg to the writer.
+    ///
+    ///A figure can be rendered again if all of its panels can, see [`Plotter::render`].
+    ///A figure without any panels returns [`Error::InvalidData`].
+    pub fn render<T: fmt::Write>(&mut self, writer: T) -> Result<T, Error> {
+        if self.panels.is_empty() {
+            return Err(Error::InvalidData("no plots"));
+        }
+        for panel in self.panels.iter() {
+            panel.check_not_consumed()?;
+        }
+
+        let mut bounds = Vec::with_capacity(self.panels.len());
+        for panel in self.panels.iter_mut() {
+            bounds.push(panel.bounds()?);
+        }
+        self.share_bounds(&mut bounds);
+
+        let columns = self.columns;
+        let rows = self.panels.len().div_ceil(columns);
+        let width = WIDTH * columns as f64;
+        let height = TITLE_HEIGHT + HEIGHT * rows as f64;
+
+        let mut root = tagger::Element::new(writer);
+
+        error::capture(|c| {
+            root.elem("svg", |writer| {
+                let svg = writer.write(|w| {
+                    w.attr("class", CLASS)?
+                        .attr("width", width)?
+                        .attr("height", height)?
+                        .with_attr("viewBox", wr!("0 0 {} {}", width, height))?
+                        .attr("xmlns", XMLNS)
+                })?;
+
+                write!(svg.get_writer(), "{}", self.header)?;
+
+                svg.single("rect", |w| {
+                    w.attr("class", "poloto_background")?
+                        .attr("fill", "white")?
+                        .attr("x", 0)?
+                        .attr("y", 0)?
+                        .attr("width", width)?
+                        .attr("height", height)
+                })?;
+
+                svg.elem("text", |writer| {
+                    let text = writer.write(|w| {
+                        w.attr("class", "poloto_text")?
+                            .attr("alignment-baseline", "middle")?
+                            .attr("text-anchor", "middle")?
+                            .attr("font-size", "xx-large")?
+                            .attr("x", width / 2.0)?
+                            .attr("y", TITLE_HEIGHT / 2.0)
+                    })?;
+                    write!(text, "{}", self.title)?;
+                    Ok(text)
+                })?;
+
+                let len = self.panels.len();
+                for (i, (panel, bounds)) in self.panels.iter_mut().zip(bounds).enumerate() {
+                    let (row, column) = (i / columns, i % columns);
+
+                    //There is no panel below the last panel of a column.
+                    let bottom = i + columns >= len;
+                    let edges = render::Edges {
+                        xlabels: !self.share_x || bottom,
+                        ylabels: !self.share_y || column == 0,
+                        background: true,
+                    };
+
+                    svg.elem("svg", |writer| {
+                        let inner = writer.write(|w| {
+                            w.attr("x", WIDTH * column as f64)?
+                                .attr("y", TITLE_HEIGHT + HEIGHT * row as f64)?
+                                .attr("width", WIDTH)?
+                                .attr("height", HEIGHT)
+                        })?;
+                        c.hold(panel.draw(inner.get_writer(), bounds, edges))?;
+                        Ok(inner)
+                    })?;
+                }
+                Ok(svg)
+            })
+        })?;
+
+        Ok(root.into_writer())
+    }
+
+    //Replace the bounds of each panel with the bounds of all panels for the shared axes.
+    fn share_bounds(&self, bounds: &mut [render::Bounds]) {
+        let all = |f: &dyn Fn(&render::Bounds) -> Option<[f64; 4]>| {
+            bounds.iter().map(f).fold(None, util::merge_bounds)
+        };
+        let x = all(&|b| util::merge_bounds(b.primary, b.secondary));
+        let y = all(&|b| b.primary);
+
+        for b in bounds.iter_mut() {
+            if let (true, Some([minx, maxx, _, _])) = (self.share_x, x) {
+                for bound in b.primary.iter_mut().chain(b.secondary.iter_mut()) {
+                    bound[0] = minx;
+                    bound[1] = maxx;
+                }
+            }
+            if let (true, Some([_, _, miny, maxy]), Some(bound)) = (self.share_y, y, &mut b.primary)
+            {
+                bound[2] = miny;
+                bound[3] = maxy;
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//Self-contained html output, with a script to explore the plot.
+use super::*;
+
+impl<'a, D: Names> Plotter<'a, D> {
+    /// Render a self-contained html page with the svg and a small script to explore it.
+    ///
+    /// * Scroll over the plot area to zoom in and out, and drag it to pan. Double click to reset.
+    /// * Click on the legend entry of a plot to hide or show it.
+    /// * The data coordinates under the pointer are shown in the top left corner of the plot area.
+    ///
+    /// The tick labels don't change when zooming, so the readout is the way to get coordinates.
+    /// Plots are always clipped to the plot area, even if they were added with [`Plotter::clip`] off.
+    /// The page doesn't load any external resources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    /// let html = plotter.render_html(String::new()).unwrap();
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// ```
+    pub fn render_html<T: fmt::Write>(&mut self, mut writer: T) -> Result<T, Error> {
+        self.check_not_consumed()?;
+
+        write!(
+            writer,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n",
+            util::Escape(moveable_format(|f| self.names.write_title(f))),
+            STYLE
+        )?;
+
+        let options = RenderOptions {
+            interactive: true,
+            ..self.options
+        };
+        let mut writer = self.render_with(writer, SvgTagOption::Svg, options)?;
+
+        write!(writer, "\n<script>{}</script>\n</body>\n</html>\n", SCRIPT)?;
+        Ok(writer)
+    }
+}
+
+const STYLE: &str = r###"
+.poloto_viewport *{vector-effect:non-scaling-stroke;}
+.poloto_crosshair line{stroke-width:1;stroke-dasharray:4 4;}
+"###;
+
+//Reads how data coordinates map to svg coordinates from the plot area rect,
+//and moves everything drawn at data coordinates into a viewport that is clipped to it.
+const SCRIPT: &str = r###"
+(function () {
+  var NS = "http://www.w3.org/2000/svg";
+  var count = 0;
+
+  function elem(tag, attrs) {
+    var e = document.createElementNS(NS, tag);
+    for (var a in attrs) {
+      e.setAttribute(a, attrs[a]);
+    }
+    return e;
+  }
+
+  function format(a) {
+    return String(parseFloat(a.toPrecision(4)));
+  }
+
+  document.querySelectorAll("svg.poloto").forEach(function (svg) {
+    var area = svg.querySelector(".poloto_plot_area");
+    if (!area) {
+      return;
+    }
+    var id = "poloto_zoom_clip_" + count++;
+
+    var ax = +area.getAttribute("x");
+    var ay = +area.getAttribute("y");
+    var aw = +area.getAttribute("width");
+    var ah = +area.getAttribute("height");
+
+    //Each axis is its data range followed by the svg range it is mapped to.
+    var axes = [];
+    ["x", "y", "y2"].forEach(function (name) {
+      var a = area.getAttribute("data-" + name);
+      if (a) {
+        axes.push({ name: name, d: a.split(" ").map(Number) });
+      }
+    });
+
+    var clip = elem("clipPath", { id: id });
+    clip.appendChild(elem("rect", { x: ax, y: ay, width: aw, height: ah }));
+    var outer = elem("g", { "clip-path": "url(#" + id + ")" });
+    var viewport = elem("g", { class: "poloto_viewport" });
+    outer.appendChild(viewport);
+    area.parentNode.insertBefore(clip, area.nextSibling);
+    clip.parentNode.insertBefore(outer, clip.nextSibling);
+
+    svg.querySelectorAll("[data-series], .poloto_annotation").forEach(function (e) {
+      e.removeAttribute("clip-path");
+      viewport.appendChild(e);
+    });
+
+    var cross = elem("g", { class: "poloto_crosshair", "pointer-events": "none" });
+    var vline = elem("line", { class: "poloto_axis_lines", stroke: "black" });
+    var hline = elem("line", { class: "poloto_axis_lines", stroke: "black" });
+    var readout = elem("text", {
+      class: "poloto_text",
+      "alignment-baseline": "hanging",
+      x: ax + 5,
+      y: ay + 5
+    });
+    cross.appendChild(vline);
+    cross.appendChild(hline);
+    cross.appendChild(readout);
+    cross.style.display = "none";
+    svg.appendChild(cross);
+
+    var k = 1, tx = 0, ty = 0;
+    var drag = null;
+
+    function update() {
+      viewport.setAttribute("transform", "translate(" + tx + "," + ty + ") scale(" + k + ")");
+    }
+
+    function point(e) {
+      var p = svg.createSVGPoint();
+      p.x = e.clientX;
+      p.y = e.clientY;
+      return p.matrixTransform(svg.getScreenCTM().inverse());
+    }
+
+    function inside(p) {
+      return p.x >= ax && p.x <= ax + aw && p.y >= ay && p.y <= ay + ah;
+    }
+
+    function move(e) {
+      var p = point(e);
+      if (drag) {
+        tx = p.x - drag.x;
+        ty = p.y - drag.y;
+        update();
+      }
+      if (!inside(p)) {
+        cross.style.display = "none";
+        return;
+      }
+      cross.style.display = "";
+      vline.setAttribute("x1", p.x);
+      vline.setAttribute("x2", p.x);
+      vline.setAttribute("y1", ay);
+      vline.setAttribute("y2", ay + ah);
+      hline.setAttribute("x1", ax);
+      hline.setAttribute("x2", ax + aw);
+      hline.setAttribute("y1", p.y);
+      hline.setAttribute("y2", p.y);
+
+      //Undo the zoom, then map back to data coordinates.
+      var q = { x: (p.x - tx) / k, y: (p.y - ty) / k };
+      readout.textContent = axes
+        .map(function (a) {
+          var d = a.d;
+          var v = a.name === "x" ? q.x : q.y;
+          return a.name + " = " + format(d[0] + ((v - d[2]) / (d[3] - d[2])) * (d[1] - d[0]));
+        })
+        .join("  ");
+    }
+
+    svg.addEventListener(
+      "wheel",
+      function (e) {
+        var p = point(e);
+        if (!inside(p)) {
+          return;
+        }
+        e.preventDefault();
+        var f = Math.exp(-e.deltaY * 0.002);
+        k *= f;
+        tx = p.x - (p.x - tx) * f;
+        ty = p.y - (p.y - ty) * f;
+        update();
+        move(e);
+      },
+      { passive: false }
+    );
+
+    svg.addEventListener("pointerdown", function (e) {
+      var p = point(e);
+      if (inside(p)) {
+        drag = { x: p.x - tx, y: p.y - ty };
+        svg.setPointerCapture(e.pointerId);
+      }
+    });
+    svg.addEventListener("pointerup", function () {
+      drag = null;
+    });
+    svg.addEventListener("pointermove", move);
+    svg.addEventListener("pointerleave", function () {
+      cross.style.display = "none";
+    });
+    svg.addEventListener("dblclick", function (e) {
+      if (inside(point(e))) {
+        k = 1;
+        tx = 0;
+        ty = 0;
+        update();
+      }
+    });
+
+    svg.querySelectorAll("[data-legend]").forEach(function (legend) {
+      legend.style.cursor = "pointer";
+      legend.addEventListener("click", function () {
+        var i = legend.getAttribute("data-legend");
+        var series = svg.querySelectorAll('[data-series="' + i + '"]');
+        var hidden = series.length > 0 && series[0].style.display === "none";
+        series.forEach(function (e) {
+          e.style.display = hidden ? "" : "none";
+        });
+        svg.querySelectorAll('[data-legend="' + i + '"]').forEach(function (e) {
+          e.style.opacity = hidden ? "" : "0.4";
+        });
+      });
+    });
+  });
+})();
+"###;
@@ -1,96 +1,222 @@
+use crate::PlotPoint;
 pub use file::*;
 mod file {
     use super::*;
-    use std::path::Path;
+    use std::fs::File;
+    use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+    use std::path::{Path, PathBuf};
+
     ///[`PlotIterator`] provides two ways to create a [`DoubleIterator`].
     ///A third option is to use the iterator only once, but instead
     ///of storing in memory, we use a file as a buffer.
     ///
     ///This way we only use the iterator once, and also don't need to store all the results
-    ///in memory.
+    ///in memory. Each point is stored as two little-endian `f64`s, so they are read back exactly.
+    ///
+    ///Writing to and reading from the file can fail, so this is a [`TryDoubleIterator`].
     ///
     ///We dont auto implement this for iterator types since
-    ///it is specilized for `[f64;2]`.
-    pub struct FileBuffer<P: AsRef<Path>, I: Iterator<Item = [f64; 2]>> {
-        path: P,
-        file: std::io::BufWriter<std::fs::File>,
+    ///it is specilized for `[f64;2]`. Points are converted to `[f64;2]` as they are stored.
+    pub struct FileBuffer<I: Iterator>
+    where
+        I::Item: PlotPoint,
+    {
+        file: BufWriter<File>,
+        temp: Option<TempFile>,
         inner: I,
     }
 
-    /// Create a [`DoubleIterator`] that uses a file buffer.
-    pub fn file_buffer<P: AsRef<Path>, I: IntoIterator<Item = [f64; 2]>>(
+    /// Create a [`TryDoubleIterator`] that uses a file in [`std::env::temp_dir`] as a buffer.
+    ///
+    /// On unix, the file is unlinked as soon as it is created, so other processes can't
+    /// find it and it isn't left behind if the process is killed.
+    /// On other platforms, the file is removed once the plot is done with it.
+    /// Returns an error if the file could not be created.
+    pub fn file_buffer<I: IntoIterator>(inner: I) -> std::io::Result<FileBuffer<I::IntoIter>>
+    where
+        I::Item: PlotPoint,
+    {
+        let (file, temp) = TempFile::create()?;
+        Ok(FileBuffer::new(inner, file, Some(temp)))
+    }
+
+    /// Create a [`TryDoubleIterator`] that uses the file at `path` as a buffer.
+    ///
+    /// Unlike [`file_buffer`], the file is left behind so it can be inspected.
+    /// Returns an error if the file could not be created.
+    pub fn file_buffer_at<P: AsRef<Path>, I: IntoIterator>(
         inner: I,
         path: P,
-    ) -> FileBuffer<P, I::IntoIter> {
-        FileBuffer::new(inner, path)
+    ) -> std::io::Result<FileBuffer<I::IntoIter>>
+    where
+        I::Item: PlotPoint,
+    {
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(FileBuffer::new(inner, file, None))
     }
 
-    impl<P: AsRef<Path>, I: Iterator<Item = [f64; 2]>> FileBuffer<P, I> {
-        /// Constructor
-        fn new<J>(inner: J, path: P) -> Self
+    impl<I: Iterator> FileBuffer<I>
+    where
+        I::Item: PlotPoint,
+    {
+        fn new<J>(inner: J, file: File, temp: Option<TempFile>) -> Self
         where
             J: IntoIterator<IntoIter = I>,
         {
-            let file = std::fs::File::create(&path).unwrap();
             FileBuffer {
-                path,
-                file: std::io::BufWriter::new(file),
+                file: BufWriter::new(file),
+                temp,
                 inner: inner.into_iter(),
             }
         }
     }
 
-    impl<P: AsRef<Path>, I: Iterator<Item = [f64; 2]>> Iterator for FileBuffer<P, I> {
+    impl<I: Iterator> TryDoubleIterator for FileBuffer<I>
+    where
+        I::Item: PlotPoint,
+    {
         type Item = [f64; 2];
-        fn next(&mut self) -> Option<Self::Item> {
-            if let Some(a) = self.inner.next() {
-                use std::io::Write;
-                writeln!(self.file, "{},{}", a[0], a[1]).unwrap();
-                Some(a)
-            } else {
-                None
-            }
-        }
-    }
-
-    impl<P: AsRef<Path>, I: Iterator<Item = [f64; 2]>> DoubleIterator for FileBuffer<P, I> {
+        type Error = crate::Error;
         type Next = FileBufferRead;
-        fn finish_first(mut self) -> Self::Next {
-            use std::io::BufRead;
-            use std::io::Seek;
-            use std::io::SeekFrom;
-            use std::io::Write;
-            self.file.flush().unwrap();
-            self.file.seek(SeekFrom::Start(0)).unwrap();
-            let f = std::fs::File::open(self.path).unwrap();
-            FileBufferRead {
-                lines: std::io::BufReader::new(f).lines(),
+        fn try_next(&mut self) -> Option<Result<[f64; 2], Self::Error>> {
+            let a = self.inner.next()?.to_point();
+            let res = self
+                .file
+                .write_all(&a[0].to_le_bytes())
+                .and_then(|_| self.file.write_all(&a[1].to_le_bytes()));
+            Some(match res {
+                Ok(()) => Ok(a),
+                Err(e) => Err(e.into()),
+            })
+        }
+        fn try_finish_first(self) -> Self::Next {
+            let FileBuffer { file, temp, .. } = self;
+            let reader = file
+                .into_inner()
+                .map_err(|e| e.into_error())
+                .and_then(|mut f| f.seek(SeekFrom::Start(0)).map(|_| f))
+                .map(BufReader::new);
+            match reader {
+                Ok(reader) => FileBufferRead {
+                    reader: Some(reader),
+                    error: None,
+                    _temp: temp,
+                },
+                Err(e) => FileBufferRead {
+                    reader: None,
+                    error: Some(e.into()),
+                    _temp: temp,
+                },
             }
         }
     }
 
     /// Iterate over the plots that were stored to a file.
     pub struct FileBufferRead {
-        lines: std::io::Lines<std::io::BufReader<std::fs::File>>,
+        reader: Option<BufReader<File>>,
+        //An error from rewinding the file, returned on the first call to next.
+        error: Option<crate::Error>,
+        //Declared after the reader so the file is closed before it is removed.
+        _temp: Option<TempFile>,
+    }
+
+    fn read_point(r: &mut impl Read) -> Result<Option<[f64; 2]>, crate::Error> {
+        let mut buf = [0; 16];
+        let mut n = 0;
+        while n < buf.len() {
+            match r.read(&mut buf[n..]) {
+                Ok(0) => break,
+                Ok(k) => n += k,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        match n {
+            0 => Ok(None),
+            16 => {
+                let mut x = [0; 8];
+                let mut y = [0; 8];
+                x.copy_from_slice(&buf[..8]);
+                y.copy_from_slice(&buf[8..]);
+                Ok(Some([f64::from_le_bytes(x), f64::from_le_bytes(y)]))
+            }
+            n => Err(crate::Error::FileBufferParse(format!(
+                "found a truncated point of {} bytes",
+                n
+            ))),
+        }
     }
 
     impl Iterator for FileBufferRead {
-        type Item = [f64; 2];
+        type Item = Result<[f64; 2], crate::Error>;
         fn next(&mut self) -> Option<Self::Item> {
-            if let Some(a) = self.lines.next() {
-                match a {
-                    Ok(a) => {
-                        let mut i = a.split(',');
-                        let aa: f64 = i.next().unwrap().parse().unwrap();
-                        let bb: f64 = i.next().unwrap().parse().unwrap();
-                        Some([aa, bb])
-                    }
-                    Err(e) => {
-                        panic!("parse error {:?}", e);
+            if let Some(e) = self.error.take() {
+                return Some(Err(e));
+            }
+            match read_point(self.reader.as_mut()?) {
+                Ok(a) => a.map(Ok),
+                Err(e) => {
+                    self.reader = None;
+                    Some(Err(e))
+                }
+            }
+        }
+    }
+
+    //A file in the temp dir that is removed on drop.
+    //On unix it is removed right after it is opened, so there is no path left to remove.
+    struct TempFile {
+        path: Option<PathBuf>,
+    }
+
+    impl TempFile {
+        fn create() -> std::io::Result<(File, TempFile)> {
+            use std::sync::atomic::{AtomicUsize, Ordering};
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+            let dir = std::env::temp_dir();
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.subsec_nanos())
+                .unwrap_or(0);
+            loop {
+                let path = dir.join(format!(
+                    "poloto-{}-{}-{}.bin",
+                    std::process::id(),
+                    COUNTER.fetch_add(1, Ordering::Relaxed),
+                    nanos
+                ));
+                match std::fs::OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .create_new(true)
+                    .open(&path)
+                {
+                    Ok(file) => {
+                        //The open file handle keeps the data around until it is closed,
+                        //even if the process is killed. If unlinking fails, remove it on drop instead.
+                        #[cfg(unix)]
+                        let path = std::fs::remove_file(&path).err().map(|_| path);
+                        #[cfg(not(unix))]
+                        let path = Some(path);
+                        return Ok((file, TempFile { path }));
                     }
+                    Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                    Err(e) => return Err(e),
                 }
-            } else {
-                None
+            }
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            if let Some(path) = &self.path {
+                let _ = std::fs::remove_file(path);
             }
         }
     }
@@ -123,6 +249,35 @@ where
 
 impl<I: IntoIterator + Sized> PlotIterator for I {}
 
+///Create a [`TryDoubleIterator`] that uses an iterator of results just once,
+///and stores the plots in a Vec for the second iteration.
+pub fn try_buffer_iter<E, P, I: IntoIterator<Item = Result<P, E>>>(
+    a: I,
+) -> TryBufferIter<I::IntoIter, P> {
+    let i = a.into_iter();
+    let ll = i.size_hint().0;
+    TryBufferIter {
+        inner: i,
+        buffer: Vec::with_capacity(ll),
+    }
+}
+
+///Create a [`TryDoubleIterator`] that uses an iterator of results twice
+///by cloning it once.
+pub fn try_twice_iter<E, P, I: IntoIterator<Item = Result<P, E>>>(
+    a: I,
+) -> TryNoBufferIter<I::IntoIter>
+where
+    I::IntoIter: Clone,
+{
+    let i = a.into_iter();
+    let sec = i.clone();
+    TryNoBufferIter {
+        inner: i,
+        inner2: sec,
+    }
+}
+
 ///Trait that is implemented for all iterators through a blanket impl.
 pub trait PlotIterator: IntoIterator + Sized {
     ///Create a [`DoubleIterator`] that uses an iterator just once,
@@ -139,6 +294,25 @@ pub trait PlotIterator: IntoIterator + Sized {
     {
         self::twice_iter(self)
     }
+
+    ///Create a [`TryDoubleIterator`] that uses an iterator of results just once,
+    ///and stores the plots in a Vec for the second iteration.
+    fn try_buffer_iter<E, P>(self) -> TryBufferIter<Self::IntoIter, P>
+    where
+        Self: IntoIterator<Item = Result<P, E>>,
+    {
+        self::try_buffer_iter(self)
+    }
+
+    ///Create a [`TryDoubleIterator`] that uses an iterator of results twice
+    ///by cloning it once.
+    fn try_twice_iter<E, P>(self) -> TryNoBufferIter<Self::IntoIter>
+    where
+        Self: IntoIterator<Item = Result<P, E>>,
+        Self::IntoIter: Clone,
+    {
+        self::try_twice_iter(self)
+    }
 }
 
 ///The trait that plot functions accept.
@@ -153,6 +327,17 @@ pub trait PlotIterator: IntoIterator + Sized {
 pub trait DoubleIterator: Iterator {
     type Next: Iterator<Item = Self::Item>;
     fn finish_first(self) -> Self::Next;
+
+    ///Create a copy of this iterator that starts from the beginning,
+    ///if the data can be iterated over again.
+    ///This is what allows a [`Plotter`](crate::Plotter) to be rendered more than once.
+    ///By default, this returns `None`.
+    fn restart(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 /// Created by [`PlotIterator::buffer_iter`]
@@ -185,13 +370,14 @@ where
     }
 }
 
-/// Created by [`PlotIterator::buffer_iter`]
+/// Created by [`PlotIterator::twice_iter`]
+#[derive(Clone)]
 pub struct NoBufferIter<I: Iterator> {
     inner: I,
     inner2: I,
 }
 
-impl<I: Iterator> DoubleIterator for NoBufferIter<I>
+impl<I: Iterator + Clone> DoubleIterator for NoBufferIter<I>
 where
     I::Item: Copy,
 {
@@ -199,6 +385,9 @@ where
     fn finish_first(self) -> Self::Next {
         self.inner2
     }
+    fn restart(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 impl<I: Iterator> Iterator for NoBufferIter<I>
@@ -210,3 +399,102 @@ where
         self.inner.next()
     }
 }
+
+///The trait that plot functions accept.
+///It is like a [`DoubleIterator`], except either iteration can fail.
+///If an error is returned, the plot stops being iterated and the error
+///is returned when rendering.
+///
+///All [`DoubleIterator`]s over a [`PlotPoint`] implement this trait through a blanket impl.
+pub trait TryDoubleIterator {
+    type Item: PlotPoint;
+    type Error: Into<Box<dyn std::error::Error + Send + Sync>>;
+    type Next: Iterator<Item = Result<Self::Item, Self::Error>>;
+
+    ///Get the next item of the first iteration.
+    fn try_next(&mut self) -> Option<Result<Self::Item, Self::Error>>;
+
+    ///Produce the iterator for the second iteration.
+    fn try_finish_first(self) -> Self::Next;
+
+    ///Create a copy of this iterator that starts from the beginning,
+    ///if the data can be iterated over again.
+    ///This is what allows a [`Plotter`](crate::Plotter) to be rendered more than once.
+    ///By default, this returns `None`.
+    fn try_restart(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+impl<D: DoubleIterator> TryDoubleIterator for D
+where
+    D::Item: PlotPoint,
+{
+    type Item = D::Item;
+    type Error = core::convert::Infallible;
+    type Next = core::iter::Map<D::Next, fn(D::Item) -> Result<D::Item, Self::Error>>;
+    fn try_next(&mut self) -> Option<Result<D::Item, Self::Error>> {
+        self.next().map(Ok)
+    }
+    fn try_finish_first(self) -> Self::Next {
+        self.finish_first().map(Ok)
+    }
+    fn try_restart(&self) -> Option<Self> {
+        self.restart()
+    }
+}
+
+/// Created by [`PlotIterator::try_buffer_iter`]
+pub struct TryBufferIter<I: Iterator, P> {
+    inner: I,
+    buffer: Vec<P>,
+}
+
+impl<E, P: PlotPoint + Copy, I: Iterator<Item = Result<P, E>>> TryDoubleIterator
+    for TryBufferIter<I, P>
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Item = P;
+    type Error = E;
+    type Next = core::iter::Map<std::vec::IntoIter<P>, fn(P) -> Result<P, E>>;
+    fn try_next(&mut self) -> Option<Result<P, E>> {
+        let a = self.inner.next()?;
+        if let Ok(a) = a {
+            self.buffer.push(a);
+        }
+        Some(a)
+    }
+    fn try_finish_first(self) -> Self::Next {
+        self.buffer.into_iter().map(Ok)
+    }
+}
+
+/// Created by [`PlotIterator::try_twice_iter`]
+#[derive(Clone)]
+pub struct TryNoBufferIter<I: Iterator> {
+    inner: I,
+    inner2: I,
+}
+
+impl<E, P: PlotPoint, I: Iterator<Item = Result<P, E>> + Clone> TryDoubleIterator
+    for TryNoBufferIter<I>
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Item = P;
+    type Error = E;
+    type Next = I;
+    fn try_next(&mut self) -> Option<Result<P, E>> {
+        self.inner.next()
+    }
+    fn try_finish_first(self) -> Self::Next {
+        self.inner2
+    }
+    fn try_restart(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
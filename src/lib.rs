@@ -4,9 +4,14 @@
 //! ### Usage
 //!
 //! Check out the [github examples](https://github.com/tiby312/poloto/tree/master/examples).
-//! * Plots containing NaN or Infinity are ignored.
+//! * Plots accept any [`PlotPoint`], like `[f64;2]`, `(i32,i32)` or `[f32;2]`.
+//! * Points containing NaN or Infinity are ignored. They leave a gap in lines and fills.
 //! * After 8 plots, the colors cycle back and are repeated.
 //!
+//! ### Features
+//!
+//! * `png` - Adds `Plotter::render_png`, which rasterizes the svg to a png on the cpu.
+//!
 use core::fmt::Write;
 
 pub use tagger;
@@ -23,59 +28,198 @@ use core::fmt;
 
 mod render;
 
-use iter::DoubleIterator;
+mod decimate;
+pub use decimate::Decimation;
+mod sample;
+mod stack;
+pub use stack::StackedArea;
+
+mod annotate;
+use annotate::{Annotation, AnnotationKind};
+
+mod html;
+
+mod terminal;
+
+#[cfg(feature = "png")]
+mod png;
+
+mod figure;
+pub use figure::Figure;
+
+mod report;
+pub use report::Report;
 
-///Contains the [`DoubleIterator`] trait and three different
-///implementers of it.
+mod error;
+pub use error::Error;
+
+mod point;
+pub use point::PlotPoint;
+
+use iter::TryDoubleIterator;
+
+///Contains the [`DoubleIterator`](iter::DoubleIterator) and
+///[`TryDoubleIterator`] traits and different
+///implementers of them.
 pub mod iter;
 
 trait PlotTrait {
     fn write_name(&self, a: &mut fmt::Formatter) -> fmt::Result;
-    fn iter_first(&mut self) -> &mut dyn Iterator<Item = [f64; 2]>;
-    fn iter_second(&mut self) -> &mut dyn Iterator<Item = [f64; 2]>;
+    fn iter_first(&mut self) -> Box<dyn Iterator<Item = [f64; 2]> + '_>;
+    fn iter_second(&mut self) -> Box<dyn Iterator<Item = [f64; 2]> + '_>;
+    //The error that ended either iteration early, if there was one.
+    fn take_error(&mut self) -> Option<Error>;
+    //True if a previous render used up the data and it can't be restarted.
+    fn is_consumed(&self) -> bool;
 }
 
 use fmt::Display;
-struct PlotStruct<D: DoubleIterator, F: Display> {
+struct PlotStruct<D: TryDoubleIterator, F: Display> {
+    //The untouched iterator. It is only used up by a render if it can't be restarted.
+    source: Option<D>,
     a: Option<D>,
-    b: Option<D::Next>,
+    error: Option<Error>,
     func: F,
 }
 
-impl<I: DoubleIterator<Item = [f64; 2]>, F: Display> PlotStruct<I, F> {
+impl<I: TryDoubleIterator, F: Display> PlotStruct<I, F> {
     fn new(it: I, func: F) -> Self {
         PlotStruct {
-            a: Some(it),
-            b: None,
+            source: Some(it),
+            a: None,
+            error: None,
             func,
         }
     }
 }
 
-impl<D: DoubleIterator<Item = [f64; 2]>, F: Display> PlotTrait for PlotStruct<D, F> {
+//Iterate until the first error, and store it.
+//Points are converted to `[f64;2]` here, before anything else sees them.
+fn stop_at_error<'a, P: PlotPoint, E: Into<Box<dyn std::error::Error + Send + Sync>>>(
+    mut next: impl FnMut() -> Option<Result<P, E>> + 'a,
+    error: &'a mut Option<Error>,
+) -> Box<dyn Iterator<Item = [f64; 2]> + 'a> {
+    Box::new(core::iter::from_fn(move || {
+        if error.is_some() {
+            return None;
+        }
+        match next()? {
+            Ok(a) => Some(a.to_point()),
+            Err(e) => {
+                *error = Some(Error::from_iter_error(e));
+                None
+            }
+        }
+    }))
+}
+
+impl<D: TryDoubleIterator, F: Display> PlotTrait for PlotStruct<D, F> {
     fn write_name(&self, a: &mut fmt::Formatter) -> fmt::Result {
         self.func.fmt(a)
     }
-    fn iter_first(&mut self) -> &mut dyn Iterator<Item = [f64; 2]> {
-        self.a.as_mut().unwrap()
+    fn iter_first(&mut self) -> Box<dyn Iterator<Item = [f64; 2]> + '_> {
+        let source = self.source.as_ref().unwrap();
+        self.a = match source.try_restart() {
+            Some(a) => Some(a),
+            None => self.source.take(),
+        };
+        let a = self.a.as_mut().unwrap();
+        stop_at_error(move || a.try_next(), &mut self.error)
     }
 
-    fn iter_second(&mut self) -> &mut dyn Iterator<Item = [f64; 2]> {
-        self.b = Some(self.a.take().unwrap().finish_first());
-        self.b.as_mut().unwrap()
+    fn iter_second(&mut self) -> Box<dyn Iterator<Item = [f64; 2]> + '_> {
+        //The second iterator is dropped along with the returned iterator,
+        //so resources like file buffers are released after drawing.
+        let mut b = self.a.take().unwrap().try_finish_first();
+        stop_at_error(move || b.next(), &mut self.error)
+    }
+
+    fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+
+    fn is_consumed(&self) -> bool {
+        self.source.is_none()
     }
 }
 
+#[derive(Copy, Clone)]
 enum PlotType {
     Scatter,
     Line,
+    Step(StepKind),
     Histo,
     LineFill,
 }
 
+//Where the vertical segment between two points of a step plot is placed.
+#[derive(Copy, Clone)]
+enum StepKind {
+    //At the x position of the left point.
+    Before,
+    //At the x position of the right point.
+    After,
+    //Halfway between both points.
+    Mid,
+}
+
+///Specify which y axis a plot is scaled against.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum YAxis {
+    ///The y axis on the left side of the graph.
+    Primary,
+    ///The y axis on the right side of the graph.
+    ///It has its own bounds and ticks.
+    Secondary,
+}
+
+///Specify what [`Plotter::line_fill`] and [`Plotter::histogram`] plots extend toward.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Baseline {
+    ///The bottom of the y axis.
+    Bottom,
+    ///The y value zero.
+    Zero,
+    ///A constant y value.
+    Constant(f64),
+}
+
+//What line fills and histogram bars extend toward.
+enum Base<'a> {
+    Value {
+        baseline: Baseline,
+        include_in_bounds: bool,
+    },
+    //Another series. Each point in the plot is paired with
+    //the point at the same index in this series.
+    Series(Box<dyn PlotTrait + 'a>),
+}
+
+//Writes the label of the point at an index.
+type PointLabels<'a> = Box<dyn Fn(usize, [f64; 2], &mut fmt::Formatter) -> fmt::Result + 'a>;
+
+//Writes the tooltip of a point.
+type Tooltips<'a> = Box<dyn Fn([f64; 2], &mut fmt::Formatter) -> fmt::Result + 'a>;
+
 struct Plot<'a> {
     plot_type: PlotType,
+    axis: YAxis,
+    decimation: Decimation,
+    clip: bool,
+    base: Base<'a>,
     plots: Box<dyn PlotTrait + 'a>,
+    labels: Option<PointLabels<'a>>,
+    tooltips: Option<Tooltips<'a>>,
+}
+
+impl Plot<'_> {
+    fn is_consumed(&self) -> bool {
+        let base = match &self.base {
+            Base::Series(base) => base.is_consumed(),
+            Base::Value { .. } => false,
+        };
+        self.plots.is_consumed() || base
+    }
 }
 
 /// Shorthand for `moveable_format(move |w|write!(w,...))`
@@ -165,6 +309,19 @@ pub fn plot<'a>(
         .build(title, xname, yname)
 }
 
+//Options that affect how all plots are rendered.
+#[derive(Copy, Clone, Default)]
+struct RenderOptions {
+    invert_x: bool,
+    invert_y: bool,
+    //Decimal places of coordinates. All of them if none.
+    precision: Option<usize>,
+    //Emit the attributes the script of the html output needs.
+    interactive: bool,
+    //Unique to each plotter, so that svgs embedded in the same html page don't share ids.
+    id: usize,
+}
+
 #[derive(Copy, Clone)]
 enum SvgTagOption {
     Svg,
@@ -183,9 +340,213 @@ pub struct Plotter<'a, D: Names> {
     names: D,
     plots: Vec<Plot<'a>>,
     svgtag: SvgTagOption,
+    axis: YAxis,
+    baseline: Baseline,
+    include_baseline: bool,
+    decimation: Decimation,
+    clip: bool,
+    options: RenderOptions,
+    annotations: Vec<Annotation<'a>>,
 }
 
 impl<'a, D: Names> Plotter<'a, D> {
+    fn check_not_consumed(&self) -> Result<(), Error> {
+        if self.plots.iter().any(|p| p.is_consumed()) {
+            Err(Error::InvalidData(
+                "plot data was used up by a previous render",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn add(&mut self, plot_type: PlotType, plots: impl PlotTrait + 'a) -> &mut Self {
+        let base = Base::Value {
+            baseline: self.baseline,
+            include_in_bounds: self.include_baseline,
+        };
+        self.add_with_base(plot_type, plots, base)
+    }
+
+    fn add_with_base(
+        &mut self,
+        plot_type: PlotType,
+        plots: impl PlotTrait + 'a,
+        base: Base<'a>,
+    ) -> &mut Self {
+        self.plots.push(Plot {
+            plot_type,
+            axis: self.axis,
+            decimation: self.decimation,
+            clip: self.clip,
+            base,
+            plots: Box::new(plots),
+            labels: None,
+            tooltips: None,
+        });
+        self
+    }
+
+    /// Assign all plots added after this call to the specified y axis.
+    /// By default, plots are added to the [`YAxis::Primary`] axis.
+    ///
+    /// If any plots are assigned to [`YAxis::Secondary`], a second
+    /// y axis with its own bounds and ticks is drawn on the right side.
+    /// Its label can be set with [`PlotterBuilder::with_secondary_yname`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("throughput",data.iter().map(|&x|x).twice_iter());
+    /// plotter.y_axis(poloto::YAxis::Secondary);
+    /// plotter.line("latency",data.iter().map(|&[x,y]|[x,y*1000.0]).twice_iter());
+    /// ```
+    pub fn y_axis(&mut self, axis: YAxis) -> &mut Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set what line fills and histogram bars added after this call extend toward.
+    /// By default, they extend toward [`Baseline::Bottom`].
+    ///
+    /// If `include_in_bounds` is true, the y axis is extended so that the baseline
+    /// is always visible. Otherwise, a baseline outside of the y axis is clamped to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,-4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,-6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.baseline(poloto::Baseline::Zero, true);
+    /// plotter.histogram("data",data.iter().map(|&x|x).twice_iter());
+    /// ```
+    pub fn baseline(&mut self, baseline: Baseline, include_in_bounds: bool) -> &mut Self {
+        self.baseline = baseline;
+        self.include_baseline = include_in_bounds;
+        self
+    }
+
+    /// Set how lines, step lines and line fills added after this call are decimated.
+    /// By default, every point is drawn.
+    ///
+    /// Fills between two series, histograms and scatter plots are not decimated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.decimation(poloto::Decimation::Lttb(500));
+    /// plotter.line("noise",(0..100_000).map(|x|[x as f64,(x as f64).sin()]).twice_iter());
+    /// ```
+    pub fn decimation(&mut self, decimation: Decimation) -> &mut Self {
+        self.decimation = decimation;
+        self
+    }
+
+    /// Set whether plots added after this call are clipped to the plot area.
+    /// By default, they are, so data outside of the axes doesn't spill
+    /// over the tick labels and legend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.clip(false);
+    /// plotter.scatter("data",data.iter().twice_iter());
+    /// ```
+    pub fn clip(&mut self, clip: bool) -> &mut Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Flip the x axis so that values decrease to the right.
+    ///
+    /// Tick labels follow the flipped direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("rankings","rank","score");
+    /// plotter.invert_x();
+    /// plotter.histogram("data",data.iter().map(|&x|x).twice_iter());
+    /// ```
+    pub fn invert_x(&mut self) -> &mut Self {
+        self.options.invert_x = true;
+        self
+    }
+
+    /// Flip the y axes so that values increase downward.
+    ///
+    /// This applies to both the primary and secondary y axis.
+    /// Fills and histogram bars extend toward the top of the graph instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("depth profile","temperature","depth");
+    /// plotter.invert_y();
+    /// plotter.line_fill("data",data.iter().map(|&x|x).twice_iter());
+    /// ```
+    pub fn invert_y(&mut self) -> &mut Self {
+        self.options.invert_y = true;
+        self
+    }
+
+    /// Round all coordinates written to the svg to this many decimal places.
+    /// By default, coordinates are written with full precision.
+    ///
+    /// The svg is usually a few hundred pixels wide, so one or two decimal places
+    /// are enough and make big plots a lot smaller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.precision(2);
+    /// plotter.line("data",data.iter().twice_iter());
+    /// ```
+    pub fn precision(&mut self, decimal_places: usize) -> &mut Self {
+        self.options.precision = Some(decimal_places);
+        self
+    }
+
     /// Create a line from plots.
     ///
     /// # Example
@@ -203,16 +564,54 @@ impl<'a, D: Names> Plotter<'a, D> {
     pub fn line(
         &mut self,
         name: impl Display + 'a,
-        plots: impl DoubleIterator<Item = [f64; 2]> + 'a,
+        plots: impl TryDoubleIterator + 'a,
     ) -> &mut Self {
-        self.plots.push(Plot {
-            plot_type: PlotType::Line,
-            plots: Box::new(PlotStruct::new(plots, name)),
-        });
-        self
+        self.add(PlotType::Line, PlotStruct::new(plots, name))
     }
 
-    /// Create a line from plots that will be filled underneath.
+    /// Create a line by sampling a function over a range of x values.
+    ///
+    /// The function is sampled more densely where it curves sharply.
+    /// It is sampled once, when this is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.function("sin(1/x)",0.05..=1.0,|x|(1.0/x).sin());
+    /// ```
+    pub fn function(
+        &mut self,
+        name: impl Display + 'a,
+        range: core::ops::RangeInclusive<f64>,
+        func: impl Fn(f64) -> f64,
+    ) -> &mut Self {
+        self.parametric(name, range, |x| [x, func(x)])
+    }
+
+    /// Create a line by sampling a parametric curve over a range of t values.
+    ///
+    /// The curve is sampled more densely where it curves sharply.
+    /// It is sampled once, when this is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.parametric("circle",0.0..=std::f64::consts::TAU,|t|[t.cos(),t.sin()]);
+    /// ```
+    pub fn parametric(
+        &mut self,
+        name: impl Display + 'a,
+        range: core::ops::RangeInclusive<f64>,
+        func: impl Fn(f64) -> [f64; 2],
+    ) -> &mut Self {
+        let points = sample::adaptive([*range.start(), *range.end()], func);
+        self.line(name, iter::twice_iter(points))
+    }
+
+    /// Create a step line from plots.
+    /// Each point's y value extends to the left until the previous point.
     ///
     /// # Example
     ///
@@ -224,21 +623,21 @@ impl<'a, D: Names> Plotter<'a, D> {
     /// ];
     /// use poloto::prelude::*;
     /// let mut plotter = poloto::plot("title","x","y");
-    /// plotter.line_fill("data",data.iter().map(|&x|x).twice_iter());
+    /// plotter.step_before("data",data.iter().map(|&x|x).twice_iter());
     /// ```
-    pub fn line_fill(
+    pub fn step_before(
         &mut self,
         name: impl Display + 'a,
-        plots: impl DoubleIterator<Item = [f64; 2]> + 'a,
+        plots: impl TryDoubleIterator + 'a,
     ) -> &mut Self {
-        self.plots.push(Plot {
-            plot_type: PlotType::LineFill,
-            plots: Box::new(PlotStruct::new(plots, name)),
-        });
-        self
+        self.add(
+            PlotType::Step(StepKind::Before),
+            PlotStruct::new(plots, name),
+        )
     }
 
-    /// Create a scatter plot from plots.
+    /// Create a step line from plots.
+    /// Each point's y value extends to the right until the next point.
     ///
     /// # Example
     ///
@@ -250,22 +649,21 @@ impl<'a, D: Names> Plotter<'a, D> {
     /// ];
     /// use poloto::prelude::*;
     /// let mut plotter = poloto::plot("title","x","y");
-    /// plotter.scatter("data",data.iter().map(|&x|x).twice_iter());
+    /// plotter.step_after("data",data.iter().map(|&x|x).twice_iter());
     /// ```
-    pub fn scatter(
+    pub fn step_after(
         &mut self,
         name: impl Display + 'a,
-        plots: impl DoubleIterator<Item = [f64; 2]> + 'a,
+        plots: impl TryDoubleIterator + 'a,
     ) -> &mut Self {
-        self.plots.push(Plot {
-            plot_type: PlotType::Scatter,
-            plots: Box::new(PlotStruct::new(plots, name)),
-        });
-        self
+        self.add(
+            PlotType::Step(StepKind::After),
+            PlotStruct::new(plots, name),
+        )
     }
 
-    /// Create a histogram from plots.
-    /// Each bar's left side will line up with a point
+    /// Create a step line from plots.
+    /// The steps happen halfway between the points.
     ///
     /// # Example
     ///
@@ -276,59 +674,723 @@ impl<'a, D: Names> Plotter<'a, D> {
     ///         [3.0,6.0]
     /// ];
     /// use poloto::prelude::*;
-    /// let mut s=String::new();
     /// let mut plotter = poloto::plot("title","x","y");
-    /// plotter.histogram("data",data.iter().map(|&x|x).twice_iter());
+    /// plotter.step_mid("data",data.iter().map(|&x|x).twice_iter());
     /// ```
-    pub fn histogram(
+    pub fn step_mid(
         &mut self,
         name: impl Display + 'a,
-        plots: impl DoubleIterator<Item = [f64; 2]> + 'a,
+        plots: impl TryDoubleIterator + 'a,
     ) -> &mut Self {
-        self.plots.push(Plot {
-            plot_type: PlotType::Histo,
-            plots: Box::new(PlotStruct::new(plots, name)),
-        });
-        self
+        self.add(PlotType::Step(StepKind::Mid), PlotStruct::new(plots, name))
     }
 
-    pub fn render_to_string(self) -> Result<String, fmt::Error> {
-        let mut s = String::new();
-        self.render(&mut s)?;
-        Ok(s)
+    /// Create a line from plots that will be filled underneath.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line_fill("data",data.iter().map(|&x|x).twice_iter());
+    /// ```
+    pub fn line_fill(
+        &mut self,
+        name: impl Display + 'a,
+        plots: impl TryDoubleIterator + 'a,
+    ) -> &mut Self {
+        self.add(PlotType::LineFill, PlotStruct::new(plots, name))
     }
 
-    pub fn render_io<T: std::io::Write>(self, writer: T) -> Result<T, fmt::Error> {
-        self.render(tagger::upgrade(writer)).map(|x| x.inner)
-    }
-    /// Render the svg to the writer.
+    /// Create a line from plots that will be filled down to another series.
     ///
-    /// Up until now, nothing has been written to the writer. We
-    /// have just accumulated a list of commands and closures. This call will
-    /// actually call all the closures and consume all the plot iterators.
-    pub fn render<T: fmt::Write>(self, writer: T) -> Result<T, fmt::Error> {
-        let Plotter {
-            names,
-            plots,
-            svgtag,
-        } = self;
-        let mut root = tagger::Element::new(writer);
-
-        use crate::build::default_tags::*;
-
-        match svgtag {
-            SvgTagOption::Svg => {
-                root.elem("svg", |writer| {
-                    let svg = writer.write(|w| default_svg_attrs(w))?;
+    /// Each point is paired with the point at the same index in `base`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line_fill_between(
+    ///     "data",
+    ///     data.iter().map(|&x|x).twice_iter(),
+    ///     data.iter().map(|&[x,y]|[x,y-2.0]).twice_iter()
+    /// );
+    /// ```
+    pub fn line_fill_between(
+        &mut self,
+        name: impl Display + 'a,
+        plots: impl TryDoubleIterator + 'a,
+        base: impl TryDoubleIterator + 'a,
+    ) -> &mut Self {
+        self.add_with_base(
+            PlotType::LineFill,
+            PlotStruct::new(plots, name),
+            Base::Series(Box::new(PlotStruct::new(base, ""))),
+        )
+    }
 
-                    render::render(svg.get_writer(), plots, names)?;
-                    Ok(svg)
-                })?;
-            }
-            SvgTagOption::NoSvg => {
-                render::render(root.get_writer(), plots, names)?;
-            }
+    /// Create a stacked area plot.
+    /// Each layer is drawn as a filled area between its own curve and the one below it,
+    /// and gets its own entry in the legend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.stacked_area(
+    ///     poloto::StackedArea::new()
+    ///         .layer("a",data.iter().copied())
+    ///         .layer("b",data.iter().copied())
+    /// );
+    /// ```
+    pub fn stacked_area(&mut self, stack: StackedArea<'a>) -> &mut Self {
+        for (name, upper, lower) in stack.into_layers() {
+            self.add_with_base(
+                PlotType::LineFill,
+                PlotStruct::new(iter::twice_iter(upper), name),
+                Base::Series(Box::new(PlotStruct::new(iter::twice_iter(lower), ""))),
+            );
+        }
+        self
+    }
+
+    /// Create a scatter plot from plots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.scatter("data",data.iter().map(|&x|x).twice_iter());
+    /// ```
+    pub fn scatter(
+        &mut self,
+        name: impl Display + 'a,
+        plots: impl TryDoubleIterator + 'a,
+    ) -> &mut Self {
+        self.add(PlotType::Scatter, PlotStruct::new(plots, name))
+    }
+
+    /// Create a histogram from plots.
+    /// Each bar's left side will line up with a point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut s=String::new();
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.histogram("data",data.iter().map(|&x|x).twice_iter());
+    /// ```
+    pub fn histogram(
+        &mut self,
+        name: impl Display + 'a,
+        plots: impl TryDoubleIterator + 'a,
+    ) -> &mut Self {
+        self.add(PlotType::Histo, PlotStruct::new(plots, name))
+    }
+
+    /// Create a histogram whose bars extend down to another series.
+    ///
+    /// Each bar extends to the point at the same index in `base`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.histogram_between(
+    ///     "data",
+    ///     data.iter().map(|&x|x).twice_iter(),
+    ///     data.iter().map(|&[x,y]|[x,y-2.0]).twice_iter()
+    /// );
+    /// ```
+    pub fn histogram_between(
+        &mut self,
+        name: impl Display + 'a,
+        plots: impl TryDoubleIterator + 'a,
+        base: impl TryDoubleIterator + 'a,
+    ) -> &mut Self {
+        self.add_with_base(
+            PlotType::Histo,
+            PlotStruct::new(plots, name),
+            Base::Series(Box::new(PlotStruct::new(base, ""))),
+        )
+    }
+
+    /// Label each point of the plot that was added last with the value returned by `func`.
+    ///
+    /// Labels are written above the points of scatter plots, and above the end of
+    /// the bars of histograms, or below it if the bar extends downward.
+    /// Other plots don't draw point labels.
+    /// They are drawn in a group with the `poloto_text` class and a class for the plot,
+    /// like `poloto0label` for the first plot.
+    /// Labels are not clipped, so labels of points on the edge of the plot area are drawn whole.
+    ///
+    /// Does nothing if no plots were added yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter
+    ///     .scatter("data",data.iter().twice_iter())
+    ///     .point_labels(|[_,y]|y);
+    /// ```
+    pub fn point_labels<L: Display>(&mut self, func: impl Fn([f64; 2]) -> L + 'a) -> &mut Self {
+        if let Some(plot) = self.plots.last_mut() {
+            plot.labels = Some(Box::new(move |_, p, f| write!(f, "{}", func(p))));
+        }
+        self
+    }
+
+    /// Label each point of the plot that was added last with the label at the same index.
+    ///
+    /// Points without a label are left unlabeled.
+    /// See [`Plotter::point_labels`] for how labels are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter
+    ///     .histogram("data",data.iter().twice_iter())
+    ///     .point_labels_from(["a","b","c"].iter());
+    /// ```
+    pub fn point_labels_from(
+        &mut self,
+        labels: impl IntoIterator<Item = impl Display + 'a>,
+    ) -> &mut Self {
+        if let Some(plot) = self.plots.last_mut() {
+            let labels: Vec<_> = labels.into_iter().collect();
+            plot.labels = Some(Box::new(move |i, _, f| match labels.get(i) {
+                Some(l) => write!(f, "{}", l),
+                None => Ok(()),
+            }));
+        }
+        self
+    }
+
+    /// Show the values of each point of the plot that was added last when it is hovered over.
+    ///
+    /// The values are written without rounding as `x, y`,
+    /// in scientific notation if they are very big or very small.
+    /// See [`Plotter::tooltips_with`] to write something else.
+    ///
+    /// A `<title>` element is added to every circle of scatter plots and every bar of histograms.
+    /// Lines, step lines and line fills get an invisible circle on every point to hover over instead.
+    /// This needs no javascript, so it works in any svg viewer that shows titles.
+    ///
+    /// Does nothing if no plots were added yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter()).tooltips();
+    /// ```
+    pub fn tooltips(&mut self) -> &mut Self {
+        if let Some(plot) = self.plots.last_mut() {
+            plot.tooltips = Some(Box::new(|[x, y], f| {
+                util::write_exact(f, x)?;
+                write!(f, ", ")?;
+                util::write_exact(f, y)
+            }));
+        }
+        self
+    }
+
+    /// Show the value returned by `func` for each point of the plot that was added last
+    /// when it is hovered over.
+    ///
+    /// See [`Plotter::tooltips`] for how tooltips are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter
+    ///     .scatter("data",data.iter().twice_iter())
+    ///     .tooltips_with(|[x,y]|format!("day {}: {} requests",x,y));
+    /// ```
+    pub fn tooltips_with<L: Display>(&mut self, func: impl Fn([f64; 2]) -> L + 'a) -> &mut Self {
+        if let Some(plot) = self.plots.last_mut() {
+            plot.tooltips = Some(Box::new(move |p, f| write!(f, "{}", func(p))));
+        }
+        self
+    }
+
+    fn annotate(&mut self, kind: AnnotationKind<'a>) -> &mut Self {
+        self.annotations.push(Annotation {
+            kind,
+            axis: self.axis,
+            clip: self.clip,
+        });
+        self
+    }
+
+    /// Write text centered on a point at data coordinates.
+    ///
+    /// Like plots, annotations are scaled against the current [`y_axis`](Plotter::y_axis)
+    /// and follow the current [`clip`](Plotter::clip) setting.
+    /// They don't change the bounds of the axes, and aren't drawn if there are no plots.
+    /// They are drawn on top of the plots, in a group with the `poloto_annotation` class.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    /// plotter.text([2.0,5.5],"peak");
+    /// ```
+    pub fn text(&mut self, pos: impl PlotPoint, text: impl Display + 'a) -> &mut Self {
+        self.annotate(AnnotationKind::Text {
+            pos: pos.to_point(),
+            text: Box::new(text),
+        })
+    }
+
+    /// Write text centered on the point `from`, with an arrow pointing from it to the point `to`.
+    ///
+    /// See [`Plotter::text`] for how annotations are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    /// plotter.arrow([1.5,5.5],[2.0,5.0],"regression introduced here");
+    /// ```
+    pub fn arrow(
+        &mut self,
+        from: impl PlotPoint,
+        to: impl PlotPoint,
+        text: impl Display + 'a,
+    ) -> &mut Self {
+        self.annotate(AnnotationKind::Arrow {
+            from: from.to_point(),
+            to: to.to_point(),
+            text: Box::new(text),
+        })
+    }
+
+    /// Draw a ring around a point at data coordinates.
+    ///
+    /// See [`Plotter::text`] for how annotations are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    /// plotter.marker([2.0,5.0]);
+    /// ```
+    pub fn marker(&mut self, pos: impl PlotPoint) -> &mut Self {
+        self.annotate(AnnotationKind::Marker {
+            pos: pos.to_point(),
+        })
+    }
+
+    /// Draw a dashed horizontal line across the plot area at a value of the y axis.
+    ///
+    /// The label is written above the right end of the line. It can be empty.
+    /// If `include_in_bounds` is true, the y axis is extended so that the line is always visible.
+    /// Otherwise, the line is only visible if it is inside the bounds of the plots.
+    ///
+    /// Like other annotations, it is scaled against the current [`y_axis`](Plotter::y_axis)
+    /// and follows the current [`clip`](Plotter::clip) setting.
+    /// See [`Plotter::text`] for how annotations are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("latency",data.iter().twice_iter());
+    /// plotter.hline(8.0,"slo",true);
+    /// ```
+    pub fn hline(
+        &mut self,
+        y: f64,
+        label: impl Display + 'a,
+        include_in_bounds: bool,
+    ) -> &mut Self {
+        self.annotate(AnnotationKind::Rule {
+            vertical: false,
+            value: y,
+            label: Box::new(label),
+            include_in_bounds,
+        })
+    }
+
+    /// Draw a dashed vertical line across the plot area at a value of the x axis.
+    ///
+    /// The label is written in the top right corner of the line. It can be empty.
+    /// See [`Plotter::hline`] for what `include_in_bounds` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("latency",data.iter().twice_iter());
+    /// plotter.vline(2.5,"today",false);
+    /// ```
+    pub fn vline(
+        &mut self,
+        x: f64,
+        label: impl Display + 'a,
+        include_in_bounds: bool,
+    ) -> &mut Self {
+        self.annotate(AnnotationKind::Rule {
+            vertical: true,
+            value: x,
+            label: Box::new(label),
+            include_in_bounds,
+        })
+    }
+
+    /// Shade the part of the plot area between two values of the x axis.
+    ///
+    /// Spans are drawn behind the plots.
+    /// The label is written in the top left corner of the span. It can be empty.
+    /// See [`Plotter::hline`] for what `include_in_bounds` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("latency",data.iter().twice_iter());
+    /// plotter.xspan(1.5..=2.5,"incident",false);
+    /// ```
+    pub fn xspan(
+        &mut self,
+        range: core::ops::RangeInclusive<f64>,
+        label: impl Display + 'a,
+        include_in_bounds: bool,
+    ) -> &mut Self {
+        self.annotate(AnnotationKind::Span {
+            vertical: true,
+            range: [*range.start(), *range.end()],
+            label: Box::new(label),
+            include_in_bounds,
+        })
+    }
+
+    /// Shade the part of the plot area between two values of the y axis.
+    ///
+    /// Spans are drawn behind the plots.
+    /// The label is written above the right end of the top of the span. It can be empty.
+    /// See [`Plotter::hline`] for what `include_in_bounds` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("latency",data.iter().twice_iter());
+    /// plotter.yspan(4.5..=5.5,"normal",false);
+    /// ```
+    pub fn yspan(
+        &mut self,
+        range: core::ops::RangeInclusive<f64>,
+        label: impl Display + 'a,
+        include_in_bounds: bool,
+    ) -> &mut Self {
+        self.annotate(AnnotationKind::Span {
+            vertical: false,
+            range: [*range.start(), *range.end()],
+            label: Box::new(label),
+            include_in_bounds,
+        })
+    }
+
+    /// Render the svg to a [`String`].
+    ///
+    /// See [`Plotter::render`] for rendering more than once.
+    pub fn render_to_string(&mut self) -> Result<String, Error> {
+        let mut s = String::new();
+        self.render(&mut s)?;
+        Ok(s)
+    }
+
+    /// Render the svg to a [`std::io::Write`].
+    ///
+    /// If writing fails, the underlying [`std::io::Error`] is returned as [`Error::Io`].
+    /// See [`Plotter::render`] for rendering more than once.
+    pub fn render_io<T: std::io::Write>(&mut self, writer: T) -> Result<T, Error> {
+        render_io(writer, |w| self.render(w).map(|_| ()))
+    }
+    /// Render the svg to the writer.
+    ///
+    /// Up until now, nothing has been written to the writer. We
+    /// have just accumulated a list of commands and closures. This call will
+    /// actually call all the closures and iterate through all the plot iterators.
+    ///
+    /// A plotter can be rendered again as long as the data of every plot
+    /// can be restarted, like data from [`twice_iter`](iter::PlotIterator::twice_iter).
+    /// Data that can only be iterated once, like from [`buffer_iter`](iter::PlotIterator::buffer_iter),
+    /// is used up by the first render. Rendering again after that returns [`Error::InvalidData`]
+    /// without writing anything.
+    /// A plotter without any plots also returns [`Error::InvalidData`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().copied().twice_iter());
+    /// let first = plotter.render_to_string().unwrap();
+    /// let second = plotter.render_to_string().unwrap();
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn render<T: fmt::Write>(&mut self, writer: T) -> Result<T, Error> {
+        self.check_not_consumed()?;
+        let (svgtag, options) = (self.svgtag, self.options);
+        self.render_with(writer, svgtag, options)
+    }
+
+    fn render_with<T: fmt::Write>(
+        &mut self,
+        writer: T,
+        svgtag: SvgTagOption,
+        options: RenderOptions,
+    ) -> Result<T, Error> {
+        let Plotter {
+            names,
+            plots,
+            annotations,
+            ..
+        } = self;
+        let mut root = tagger::Element::new(writer);
+
+        use crate::build::default_tags::*;
+
+        match svgtag {
+            SvgTagOption::Svg => {
+                error::capture(|c| {
+                    root.elem("svg", |writer| {
+                        let svg = writer.write(|w| default_svg_attrs(w))?;
+                        c.hold(render::render(
+                            svg.get_writer(),
+                            plots,
+                            annotations,
+                            names,
+                            options,
+                            true,
+                        ))?;
+                        Ok(svg)
+                    })
+                })?;
+            }
+            SvgTagOption::NoSvg => {
+                render::render(root.get_writer(), plots, annotations, names, options, true)?;
+            }
         }
         Ok(root.into_writer())
     }
+
+    /// Render the plot into the rectangle `[x,y,width,height]` of a parent svg element.
+    ///
+    /// The plot is drawn inside a nested svg viewport, so it is scaled to fit the rectangle
+    /// while keeping its aspect ratio, and is centered in it.
+    /// The svg tag option of the plotter is ignored.
+    /// If `background` is false, the background rect is left out so the parent shows through.
+    ///
+    /// The header of the plotter isn't written, so composing several plots doesn't repeat
+    /// the stylesheet. Write one header into the parent instead, like [`Figure`] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    ///
+    /// use poloto::tagger::prelude::*;
+    /// use std::fmt::Write;
+    /// let mut root = poloto::tagger::Element::new(String::new());
+    /// root.elem("svg", |w| {
+    ///     let svg = w.write(|w| w.attr("width", 1000)?.attr("height", 400))?;
+    ///     let header = poloto::build::HeaderBuilder::new().push_css_default().build();
+    ///     write!(svg.get_writer(), "{}", header)?;
+    ///     plotter.render_into(svg, [0.0, 0.0, 400.0, 250.0], true).unwrap();
+    ///     plotter.render_into(svg, [500.0, 0.0, 400.0, 250.0], false).unwrap();
+    ///     Ok(svg)
+    /// }).unwrap();
+    /// let svg = root.into_writer();
+    /// ```
+    pub fn render_into<T: fmt::Write>(
+        &mut self,
+        parent: &mut tagger::Element<T>,
+        [x, y, width, height]: [f64; 4],
+        background: bool,
+    ) -> Result<(), Error> {
+        self.check_not_consumed()?;
+
+        let Plotter {
+            names,
+            plots,
+            annotations,
+            options,
+            ..
+        } = self;
+        let options = *options;
+        let r = |a: f64| util::round(a, options.precision);
+
+        use crate::build::default_tags::*;
+        use tagger::prelude::*;
+
+        let bounds = render::find_bounds(plots, annotations)?;
+        let edges = render::Edges {
+            background,
+            ..Default::default()
+        };
+
+        error::capture(|c| {
+            parent.elem("svg", |writer| {
+                let svg = writer.write(|w| {
+                    w.attr("x", r(x))?
+                        .attr("y", r(y))?
+                        .attr("width", r(width))?
+                        .attr("height", r(height))?
+                        .with_attr("viewBox", wr!("0 0 {} {}", WIDTH, HEIGHT))
+                })?;
+
+                c.hold(render::draw(
+                    svg.get_writer(),
+                    plots,
+                    annotations,
+                    names,
+                    options,
+                    bounds,
+                    edges,
+                ))?;
+                Ok(svg)
+            })
+        })?;
+        Ok(())
+    }
+}
+
+//Render to a std::io::Write.
+//If writing fails, the underlying io error is returned instead of the fmt error.
+fn render_io<T: std::io::Write>(
+    writer: T,
+    func: impl FnOnce(&mut tagger::WriterAdaptor<T>) -> Result<(), Error>,
+) -> Result<T, Error> {
+    let mut writer = tagger::upgrade(writer);
+    match func(&mut writer) {
+        Ok(()) => Ok(writer.inner),
+        Err(Error::Fmt(e)) => match writer.error {
+            Err(e) => Err(Error::Io(e)),
+            Ok(()) => Err(Error::Fmt(e)),
+        },
+        Err(e) => Err(e),
+    }
 }
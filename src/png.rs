@@ -0,0 +1,146 @@
+//Png output, made by rasterizing the svg on the cpu.
+use super::*;
+use resvg::{tiny_skia, usvg};
+
+//The dpi that svg user units are defined at.
+const SVG_DPI: f32 = 96.0;
+
+//The most pixels a png can have, so a huge dpi fails instead of allocating a huge pixmap.
+//This is a gigabyte of rgba pixels.
+const MAX_PIXELS: u64 = 1 << 28;
+
+//Installed fonts to draw text with if a font family isn't installed, most preferred first.
+const FALLBACK_FAMILIES: [&str; 6] = [
+    "Arial",
+    "Helvetica",
+    "Liberation Sans",
+    "DejaVu Sans",
+    "Noto Sans",
+    "FreeSans",
+];
+
+impl<'a, D: Names> Plotter<'a, D> {
+    /// Render the svg and rasterize it to a png image.
+    ///
+    /// The svg is drawn at `dpi` dots per inch, where 96 gives one pixel per svg unit.
+    /// Text is drawn with the system fonts. If a font family in the css isn't installed,
+    /// the first installed of Arial, Helvetica, Liberation Sans, DejaVu Sans, Noto Sans and FreeSans
+    /// is used instead, or any installed font if none of those are.
+    /// Characters missing from that font, like emoji, are drawn with any installed font that has them.
+    /// If no fonts are installed at all, text is left out.
+    ///
+    /// The svg tag option of the plotter is ignored.
+    /// If the svg can't be rasterized, like when the image would have more than 2^28 pixels,
+    /// [`Error::Raster`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    /// let png = plotter.render_png(Vec::new(), 96.0).unwrap();
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// ```
+    pub fn render_png<T: std::io::Write>(&mut self, mut writer: T, dpi: f32) -> Result<T, Error> {
+        self.check_not_consumed()?;
+        if !(dpi.is_finite() && dpi > 0.0) {
+            return Err(Error::InvalidData("dpi is not a positive number"));
+        }
+
+        let options = self.options;
+        let svg = self.render_with(String::new(), SvgTagOption::Svg, options)?;
+
+        let mut opt = usvg::Options::default();
+        if let Some(fallback) = load_fonts(opt.fontdb_mut()) {
+            opt.font_resolver.select_font = select_font(fallback);
+        }
+
+        let tree = usvg::Tree::from_str(&svg, &opt).map_err(|e| Error::Raster(Box::new(e)))?;
+
+        let too_big = || Error::Raster("png size is too big".into());
+        let scale = dpi / SVG_DPI;
+        let size = tree
+            .size()
+            .to_int_size()
+            .scale_by(scale)
+            .filter(|s| u64::from(s.width()) * u64::from(s.height()) <= MAX_PIXELS)
+            .ok_or_else(too_big)?;
+        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).ok_or_else(too_big)?;
+
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+
+        let png = pixmap
+            .encode_png()
+            .map_err(|e| Error::Raster(Box::new(e)))?;
+        writer.write_all(&png)?;
+        Ok(writer)
+    }
+}
+
+fn installed(db: &usvg::fontdb::Database, name: &str) -> bool {
+    db.faces()
+        .any(|face| face.families.iter().any(|(family, _)| family == name))
+}
+
+//Load the system fonts, and point the generic families that aren't installed at one that is.
+//Returns the family to draw with if a font family isn't installed, if there are any fonts.
+fn load_fonts(db: &mut usvg::fontdb::Database) -> Option<String> {
+    use usvg::fontdb::Family;
+
+    db.load_system_fonts();
+
+    let fallback = FALLBACK_FAMILIES
+        .iter()
+        .find(|name| installed(db, name))
+        .map(|name| name.to_string())
+        .or_else(|| {
+            db.faces()
+                .next()
+                .and_then(|face| face.families.first())
+                .map(|(family, _)| family.clone())
+        })?;
+
+    if !installed(db, db.family_name(&Family::Serif)) {
+        db.set_serif_family(fallback.clone());
+    }
+    if !installed(db, db.family_name(&Family::SansSerif)) {
+        db.set_sans_serif_family(fallback.clone());
+    }
+    Some(fallback)
+}
+
+//Select fonts like usvg does, except that if none of the named families are installed,
+//the fallback family is used instead of the serif family.
+fn select_font(fallback: String) -> usvg::FontSelectionFn<'static> {
+    let default = usvg::FontResolver::default_font_selector();
+    Box::new(move |font, db| {
+        let id = default(font, db)?;
+        let missing = font.families().iter().all(|family| match family {
+            usvg::FontFamily::Named(name) => !installed(db, name),
+            _ => false,
+        });
+        if !missing {
+            return Some(id);
+        }
+
+        //Keep the weight, stretch and style of the serif face usvg picked.
+        let face = db.face(id)?;
+        let query = usvg::fontdb::Query {
+            families: &[usvg::fontdb::Family::Name(&fallback)],
+            weight: face.weight,
+            stretch: face.stretch,
+            style: face.style,
+        };
+        db.query(&query).or(Some(id))
+    })
+}
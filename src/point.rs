@@ -0,0 +1,52 @@
+///A type that can be plotted as a point.
+///
+///It is implemented for `[T;2]` and `(T,T)` for all primitive numeric types,
+///and for references to any [`PlotPoint`].
+///The point is converted to `[f64;2]` before it is plotted.
+///
+/// # Example
+///
+/// ```
+/// struct Sample {
+///     time: u32,
+///     value: f32,
+/// }
+/// impl poloto::PlotPoint for Sample {
+///     fn to_point(&self) -> [f64; 2] {
+///         [self.time as f64, self.value as f64]
+///     }
+/// }
+///
+/// let data = vec![Sample { time: 0, value: 2.5 }, Sample { time: 1, value: 3.0 }];
+/// use poloto::prelude::*;
+/// let mut plotter = poloto::plot("title", "x", "y");
+/// plotter.line("data", data.iter().twice_iter());
+/// ```
+pub trait PlotPoint {
+    fn to_point(&self) -> [f64; 2];
+}
+
+impl<P: PlotPoint + ?Sized> PlotPoint for &P {
+    fn to_point(&self) -> [f64; 2] {
+        (**self).to_point()
+    }
+}
+
+macro_rules! impl_plot_point {
+    ($($t:ty),*) => {
+        $(
+            impl PlotPoint for [$t; 2] {
+                fn to_point(&self) -> [f64; 2] {
+                    [self[0] as f64, self[1] as f64]
+                }
+            }
+            impl PlotPoint for ($t, $t) {
+                fn to_point(&self) -> [f64; 2] {
+                    [self.0 as f64, self.1 as f64]
+                }
+            }
+        )*
+    };
+}
+
+impl_plot_point!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
@@ -1,4 +1,5 @@
 use super::*;
+use core::cell::{Cell, RefCell};
 use tagger::prelude::*;
 
 struct WriteCounter<T: fmt::Write> {
@@ -21,91 +22,215 @@ impl<T: fmt::Write> fmt::Write for WriteCounter<T> {
     }
 }
 
-//Returns error if the user supplied format functions don't work.
-//Panics if the element tag writing writes fail
+//Returns error if the user supplied format functions don't work,
+//the data is invalid, or writing fails.
 pub(super) fn render<'a, 'x, T: Write>(
-    mut writer: &'x mut T,
-    mut plots: Vec<Plot<'a>>,
-    names: impl Names, //Box<dyn Names + 'a>,
-) -> Result<&'x mut T, fmt::Error> {
+    writer: &'x mut T,
+    plots: &mut [Plot<'a>],
+    annotations: &[Annotation],
+    names: &impl Names,
+    options: RenderOptions,
+    background: bool,
+) -> Result<&'x mut T, Error> {
     write!(writer, "{}", moveable_format(|w| names.write_header(w)))?;
+    let bounds = find_bounds(plots, annotations)?;
+    draw(
+        writer,
+        plots,
+        annotations,
+        names,
+        options,
+        bounds,
+        Edges {
+            background,
+            ..Edges::default()
+        },
+    )
+}
+
+///The bounds of the plots of each y axis, as `[minx,maxx,miny,maxy]`.
+#[derive(Copy, Clone, Default)]
+pub(super) struct Bounds {
+    pub primary: Option<[f64; 4]>,
+    pub secondary: Option<[f64; 4]>,
+}
+
+///Which tick labels to draw. A panel in a figure with shared axes
+///only draws them if it is on the bottom or left edge.
+///The background rect is left out if the plot is drawn on top of something else.
+#[derive(Copy, Clone)]
+pub(super) struct Edges {
+    pub xlabels: bool,
+    pub ylabels: bool,
+    pub background: bool,
+}
+
+impl Default for Edges {
+    fn default() -> Self {
+        Edges {
+            xlabels: true,
+            ylabels: true,
+            background: true,
+        }
+    }
+}
+
+//Go through the first iteration of every plot to find the bounds.
+//Reference lines and spans can extend the bounds, but only of an axis that has plots.
+pub(super) fn find_bounds(plots: &mut [Plot], annotations: &[Annotation]) -> Result<Bounds, Error> {
+    if plots.is_empty() {
+        return Err(Error::InvalidData("no plots"));
+    }
+
+    let mut primary = None;
+    let mut secondary = None;
+    for plot in plots.iter_mut() {
+        if let Base::Value {
+            baseline: Baseline::Constant(y),
+            ..
+        } = plot.base
+        {
+            if !y.is_finite() {
+                return Err(Error::InvalidData("baseline is not finite"));
+            }
+        }
+
+        let mut plot_bound = util::find_bounds(plot.plots.iter_first().filter(is_finite));
+
+        match &mut plot.base {
+            Base::Series(base) => {
+                plot_bound = util::merge_bounds(
+                    plot_bound,
+                    util::find_bounds(base.iter_first().filter(is_finite)),
+                );
+            }
+            &mut Base::Value {
+                baseline,
+                include_in_bounds: true,
+            } => {
+                if let (Some(b), Some(y)) = (&mut plot_bound, baseline_value(baseline)) {
+                    if let PlotType::LineFill | PlotType::Histo = plot.plot_type {
+                        b[2] = b[2].min(y);
+                        b[3] = b[3].max(y);
+                    }
+                }
+            }
+            Base::Value { .. } => {}
+        }
+
+        if let Some(e) = take_error(&mut *plot.plots, &mut plot.base) {
+            return Err(e);
+        }
+
+        let bound = match plot.axis {
+            YAxis::Primary => &mut primary,
+            YAxis::Secondary => &mut secondary,
+        };
+        *bound = util::merge_bounds(*bound, plot_bound);
+    }
 
+    for a in annotations {
+        let bound = match a.axis {
+            YAxis::Primary => &mut primary,
+            YAxis::Secondary => &mut secondary,
+        };
+        if bound.is_some() {
+            *bound = util::merge_bounds(*bound, a.bounds());
+        }
+    }
+    Ok(Bounds { primary, secondary })
+}
+
+//Draw everything except the header, using the bounds found by the first iteration.
+pub(super) fn draw<'a, 'x, T: Write>(
+    mut writer: &'x mut T,
+    plots: &mut [Plot<'a>],
+    annotations: &[Annotation],
+    names: &impl Names,
+    options: RenderOptions,
+    Bounds { primary, secondary }: Bounds,
+    edges: Edges,
+) -> Result<&'x mut T, Error> {
     use crate::build::default_tags::*;
     let width = WIDTH;
     let height = HEIGHT;
     let padding = 150.0;
     let paddingy = 100.0;
 
-    let svg = &mut tagger::Element::new(&mut writer);
+    let precision = options.precision;
+    let r = move |a: f64| util::round(a, precision);
+    let interactive = options.interactive;
 
-    svg.single("rect", |w| {
-        w.attr("class", "poloto_background")?
-            .attr("fill", "white")?
-            .attr("x", 0)?
-            .attr("y", 0)?
-            .attr("width", width)?
-            .attr("height", height)
-    })?;
+    let svg = &mut tagger::Element::new(&mut writer);
 
-    //Find range.
-    let [minx, maxx, miny, maxy] =
-        if let Some(m) = util::find_bounds(plots.iter_mut().flat_map(|x| x.plots.iter_first())) {
-            m
-        } else {
-            //TODO test that this looks ok
-            return Ok(writer); //No plots at all. don't need to draw anything
-        };
+    if edges.background {
+        svg.single("rect", |w| {
+            w.attr("class", "poloto_background")?
+                .attr("fill", "white")?
+                .attr("x", 0)?
+                .attr("y", 0)?
+                .attr("width", r(width))?
+                .attr("height", r(height))
+        })?;
+    }
 
-    const EPSILON: f64 = f64::MIN_POSITIVE * 10.0;
+    let [minx, maxx, _, _] = if let Some(m) = util::merge_bounds(primary, secondary) {
+        m
+    } else {
+        //TODO test that this looks ok
+        return Ok(writer); //No finite points at all. don't need to draw anything
+    };
 
     //Insert a range if the range is zero.
-    let [miny, maxy] = if (maxy - miny).abs() < EPSILON {
-        [miny - 1.0, miny + 1.0]
+    let [minx, maxx] = pad_empty_range([minx, maxx]);
+    let primary = primary.map(|[_, _, miny, maxy]| pad_empty_range([miny, maxy]));
+    let secondary = secondary.map(|[_, _, miny, maxy]| pad_empty_range([miny, maxy]));
+
+    //Make room for the secondary axis ticks on the right side.
+    let paddingr = if secondary.is_some() {
+        padding * 1.5
     } else {
-        [miny, maxy]
+        padding
     };
 
-    //Insert a range if the range is zero.
-    let [minx, maxx] = if (maxx - minx).abs() < EPSILON {
-        [minx - 1.0, minx + 1.0]
+    //The secondary axis label is drawn this far right, and the legend goes past it.
+    let yname2x = width - paddingr + padding * 0.55;
+    let legendx = if secondary.is_some() {
+        yname2x + padding / 5.0
     } else {
-        [minx, maxx]
+        width - padding / 1.2
     };
 
-    let scalex = (width - padding * 2.0) / (maxx - minx);
-    let scaley = (height - paddingy * 2.0) / (maxy - miny);
+    let xscale = Scale::new([minx, maxx], [padding, width - paddingr], options.invert_x);
+    let yscale = |range| Scale::new(range, [height - paddingy, paddingy], options.invert_y);
 
     {
         //Draw step lines
         //https://stackoverflow.com/questions/60497397/how-do-you-format-a-float-to-the-first-significant-decimal-and-with-specified-pr
 
         let ideal_num_xsteps = 9;
-        let ideal_num_ysteps = 10;
 
         let texty_padding = paddingy * 0.3;
-        let textx_padding = padding * 0.1;
 
         let (xstep_num, xstep, xstart_step) = util::find_good_step(ideal_num_xsteps, [minx, maxx]);
-        let (ystep_num, ystep, ystart_step) = util::find_good_step(ideal_num_ysteps, [miny, maxy]);
 
         let distance_to_firstx = xstart_step - minx;
 
-        let distance_to_firsty = ystart_step - miny;
-
         {
             //step num is assured to be atleast 1.
-            let (extra, xstart_step) = if crate::util::determine_if_should_use_strat(
-                xstart_step,
-                xstart_step + ((xstep_num - 1) as f64) * xstep,
-                xstep,
-            )? {
+            let (extra, xstart_step) = if edges.xlabels
+                && crate::util::determine_if_should_use_strat(
+                    xstart_step,
+                    xstart_step + ((xstep_num - 1) as f64) * xstep,
+                    xstep,
+                )? {
                 svg.elem("text", |writer| {
                     let text = writer.write(|w| {
                         w.attr("class", "poloto_text")?
                             .attr("alignment-baseline", "middle")?
                             .attr("text-anchor", "start")?
-                            .attr("x", width * 0.55)?
-                            .attr("y", paddingy * 0.7)
+                            .attr("x", r(width * 0.55))?
+                            .attr("y", r(paddingy * 0.7))
                     })?;
                     write!(text, "Where j = ")?;
 
@@ -122,24 +247,28 @@ pub(super) fn render<'a, 'x, T: Write>(
             for a in 0..xstep_num {
                 let p = (a as f64) * xstep;
 
-                let xx = (distance_to_firstx + p) * scalex + padding;
+                let xx = xscale.offset(distance_to_firstx + p);
 
                 svg.single("line", |w| {
                     w.attr("class", "poloto_axis_lines")?
                         .attr("stroke", "black")?
-                        .attr("x1", xx)?
-                        .attr("x2", xx)?
-                        .attr("y1", height - paddingy)?
-                        .attr("y2", height - paddingy * 0.95) //TODO operations of order?
+                        .attr("x1", r(xx))?
+                        .attr("x2", r(xx))?
+                        .attr("y1", r(height - paddingy))?
+                        .attr("y2", r(height - paddingy * 0.95)) //TODO operations of order?
                 })?;
 
+                if !edges.xlabels {
+                    continue;
+                }
+
                 svg.elem("text", |writer| {
                     let text = writer.write(|w| {
                         w.attr("class", "poloto_text")?
                             .attr("alignment-baseline", "start")?
                             .attr("text-anchor", "middle")?
-                            .attr("x", xx)?
-                            .attr("y", height - paddingy + texty_padding)
+                            .attr("x", r(xx))?
+                            .attr("y", r(height - paddingy + texty_padding))
                     })?;
                     write!(text, "{}", extra)?;
 
@@ -149,74 +278,120 @@ pub(super) fn render<'a, 'x, T: Write>(
             }
         }
 
-        {
-            //step num is assured to be atleast 1.
-            let (extra, ystart_step) = if crate::util::determine_if_should_use_strat(
-                ystart_step,
-                ystart_step + ((ystep_num - 1) as f64) * ystep,
-                ystep,
-            )? {
-                svg.elem("text", |writer| {
-                    let text = writer.write(|w| {
-                        w.attr("class", "poloto_text")?
-                            .attr("alignment-baseline", "middle")?
-                            .attr("text-anchor", "start")?
-                            .attr("x", padding)?
-                            .attr("y", paddingy * 0.7)
-                    })?;
-                    write!(text, "Where k = ")?;
-
-                    crate::util::interval_float(text, ystart_step, None)?; //Some(ystep)
-
-                    Ok(text)
-                })?;
-
-                ("k+", 0.0)
-            } else {
-                ("", ystart_step)
-            };
+        if let Some(range) = primary {
+            draw_yticks(
+                svg,
+                YAxis::Primary,
+                padding,
+                yscale(range),
+                edges.ylabels,
+                precision,
+            )?;
+        }
 
-            //Draw interval y text
-            for a in 0..ystep_num {
-                let p = (a as f64) * ystep;
+        if let Some(range) = secondary {
+            draw_yticks(
+                svg,
+                YAxis::Secondary,
+                width - paddingr,
+                yscale(range),
+                true,
+                precision,
+            )?;
+        }
+    }
 
-                let yy = height - (distance_to_firsty + p) * scaley - paddingy;
+    //The script of the html output zooms and pans the plot area, and shows the data coordinates under
+    //the pointer. It gets how data coordinates map to svg coordinates from this rect,
+    //as the data range followed by the svg range it is mapped to, for each axis.
+    if interactive {
+        let data = |s: Scale| format!("{} {} {} {}", s.range[0], s.range[1], s.start, s.end);
+        svg.single("rect", |w| {
+            w.attr("class", "poloto_plot_area")?
+                .attr("fill", "none")?
+                .attr("pointer-events", "all")?
+                .attr("x", r(padding))?
+                .attr("y", r(paddingy))?
+                .attr("width", r(width - padding - paddingr))?
+                .attr("height", r(height - paddingy * 2.0))?
+                .attr("data-x", data(xscale))?;
+            if let Some(range) = primary {
+                w.attr("data-y", data(yscale(range)))?;
+            }
+            if let Some(range) = secondary {
+                w.attr("data-y2", data(yscale(range)))?;
+            }
+            Ok(w)
+        })?;
+    }
 
-                svg.single("line", |w| {
-                    w.attr("class", "poloto_axis_lines")?
-                        .attr("stroke", "black")?
-                        .attr("x1", padding)?
-                        .attr("x2", padding * 0.96)?
-                        .attr("y1", yy)?
-                        .attr("y2", yy)
+    //The area plots are drawn inside of. It is a bit bigger than the axes,
+    //so that points on the edge of the graph are drawn whole.
+    let clip_area = {
+        let extra = padding / 30.0;
+        [
+            r(padding - extra),
+            r(paddingy - extra),
+            r(width - padding - paddingr + extra * 2.0),
+            r(height - paddingy * 2.0 + extra * 2.0),
+        ]
+    };
+    let clip_id = format!("poloto_clip_{}", options.id);
+    if plots.iter().any(|p| p.clip) || annotations.iter().any(|a| a.clip) {
+        let [x, y, w, h] = clip_area;
+        svg.elem_no_attr("defs", |defs| {
+            defs.elem("clipPath", |wr| {
+                let clip = wr.write(|wr| wr.attr("id", &clip_id))?;
+                clip.single("rect", |wr| {
+                    wr.attr("x", x)?
+                        .attr("y", y)?
+                        .attr("width", w)?
+                        .attr("height", h)
                 })?;
+                Ok(clip)
+            })?;
+            Ok(defs)
+        })?;
+    }
 
-                svg.elem("text", |writer| {
-                    let text = writer.write(|w| {
-                        w.attr("class", "poloto_text")?
-                            .attr("alignment-baseline", "middle")?
-                            .attr("text-anchor", "end")?
-                            .attr("x", padding - textx_padding)?
-                            .attr("y", yy)
-                    })?;
-                    write!(text, "{}", extra)?;
-
-                    util::interval_float(text, p + ystart_step, Some(ystep))?;
-                    Ok(text)
-                })?;
+    //A plot or annotation always has a range for its axis if there are any plots on it.
+    let axis_scale = |axis| {
+        yscale(
+            match axis {
+                YAxis::Primary => primary,
+                YAxis::Secondary => secondary,
             }
-        }
+            .unwrap_or([0.0, 1.0]),
+        )
+    };
+
+    for a in annotations.iter().filter(|a| a.is_background()) {
+        draw_annotation(
+            svg,
+            a,
+            xscale,
+            axis_scale(a.axis),
+            padding,
+            &clip_id,
+            precision,
+        )?;
     }
 
     for (
         i,
         colori,
-        Plot {
+        &mut Plot {
             plot_type,
-            mut plots,
+            axis,
+            decimation,
+            clip,
+            ref mut base,
+            ref mut plots,
+            ref labels,
+            ref tooltips,
         },
     ) in plots
-        .into_iter()
+        .iter_mut()
         .enumerate()
         .map(|(i, x)| (i, i % NUM_COLORS, x))
     {
@@ -227,12 +402,13 @@ pub(super) fn render<'a, 'x, T: Write>(
         let mut name_exists = true;
         svg.elem("text", |writer| {
             let mut text = writer.write(|w| {
-                w.attr("class", "poloto_text")?
+                mark(w, interactive, "data-legend", i)?
+                    .attr("class", "poloto_text")?
                     .attr("alignment-baseline", "middle")?
                     .attr("text-anchor", "start")?
                     .attr("font-size", "large")?
-                    .attr("x", width - padding / 1.2)?
-                    .attr("y", paddingy + (i as f64) * spacing)
+                    .attr("x", r(legendx))?
+                    .attr("y", r(paddingy + (i as f64) * spacing))
             })?;
 
             let mut c = WriteCounter::new(&mut text);
@@ -247,139 +423,372 @@ pub(super) fn render<'a, 'x, T: Write>(
         })?;
         //}
 
-        let legendx1 = width - padding / 1.2 + padding / 30.0;
+        let legendx1 = legendx + padding / 30.0;
         let legendy1 = paddingy - padding / 8.0 + (i as f64) * spacing;
 
         //Draw plots
 
-        let it = plots.iter_second().map(|[x, y]| {
-            [
-                padding + (x - minx) * scalex,
-                height - paddingy - (y - miny) * scaley,
-            ]
-        });
-
-        match plot_type {
-            PlotType::Line => {
-                //TODO better way to modularize this if statement for all plots?
-                if name_exists {
-                    svg.single("line", |w| {
-                        w.with_attr("class", wr!("poloto{}stroke", colori))?
-                            .attr("stroke", "black")?
-                            .attr("x1", legendx1)?
-                            .attr("x2", legendx1 + padding / 3.0)?
-                            .attr("y1", legendy1)?
-                            .attr("y2", legendy1)
+        let yscale = axis_scale(axis);
+
+        //Scoped so the plot iterators are dropped before checking for errors.
+        {
+            //The svg y coordinate that fills and bars extend towards.
+            //Unless they extend towards another series.
+            let (basey, base_it) = match base {
+                &mut Base::Value { baseline, .. } => {
+                    let basey = match baseline_value(baseline) {
+                        Some(y) => yscale.clamp(yscale.pos(y)),
+                        None => yscale.start,
+                    };
+                    (basey, None)
+                }
+                Base::Series(base) => (yscale.start, Some(base.iter_second())),
+            };
+
+            let base_it = base_it.map(|it| it.map(|[x, y]| [xscale.pos(x), yscale.pos(y)]));
+
+            //The index and data point of the point that was mapped last, for point labels.
+            //Scatter plots and histograms draw each point as soon as it is mapped.
+            let current = Cell::new((0, [0.0; 2]));
+
+            //Lines are decimated, so the points to hover over are recorded before that.
+            let hits = RefCell::new(Vec::new());
+            let record = tooltips.is_some()
+                && matches!(
+                    plot_type,
+                    PlotType::Line | PlotType::Step(_) | PlotType::LineFill
+                );
+
+            let it = plots.iter_second().enumerate().map(|(i, a)| {
+                current.set((i, a));
+                let p = [xscale.pos(a[0]), yscale.pos(a[1])];
+                if record {
+                    hits.borrow_mut().push((a, p));
+                }
+                p
+            });
+
+            //The points to label, as the index and data point, the svg point the label is next to,
+            //and whether the label goes below it.
+            let mut labelled: Vec<(usize, [f64; 2], [f64; 2], bool)> = Vec::new();
+            let mut label = |(index, data): (usize, [f64; 2]), at: [f64; 2], below: bool| {
+                if labels.is_some() {
+                    labelled.push((index, data, at, below));
+                }
+            };
+
+            //Points are paired up with a base series by index, so those can't be decimated.
+            let it = match (plot_type, &base_it) {
+                (PlotType::Line | PlotType::Step(_) | PlotType::LineFill, None) => {
+                    decimate::decimate(it, decimation)
+                }
+                _ => Box::new(it),
+            };
+
+            match plot_type {
+                PlotType::Line | PlotType::Step(_) => {
+                    //TODO better way to modularize this if statement for all plots?
+                    if name_exists {
+                        svg.single("line", |w| {
+                            mark(w, interactive, "data-legend", i)?
+                                .with_attr("class", wr!("poloto{}stroke", colori))?
+                                .attr("stroke", "black")?
+                                .attr("x1", r(legendx1))?
+                                .attr("x2", r(legendx1 + padding / 3.0))?
+                                .attr("y1", r(legendy1))?
+                                .attr("y2", r(legendy1))
+                        })?;
+                    }
+
+                    //Points that are not finite leave a gap in the line.
+                    //Each run of finite points is drawn as its own polyline.
+                    let mut it = it.peekable();
+                    loop {
+                        while it.next_if(|a| !is_finite(a)).is_some() {}
+                        if it.peek().is_none() {
+                            break;
+                        }
+
+                        svg.single("polyline", |w| {
+                            mark(clip_path(w, clip, &clip_id)?, interactive, "data-series", i)?
+                                .with_attr("class", wr!("poloto{}stroke", colori))?
+                                .attr("fill", "none")?
+                                .attr("stroke", "black")?
+                                .points_data(|w| {
+                                    let mut last: Option<[f64; 2]> = None;
+                                    while let Some([x, y]) = it.next_if(is_finite) {
+                                        if let (PlotType::Step(step), Some([lx, ly])) =
+                                            (plot_type, last)
+                                        {
+                                            match step {
+                                                StepKind::Before => {
+                                                    w.add_point(r(lx), r(y))?;
+                                                }
+                                                StepKind::After => {
+                                                    w.add_point(r(x), r(ly))?;
+                                                }
+                                                StepKind::Mid => {
+                                                    let mx = (lx + x) / 2.0;
+                                                    w.add_point(r(mx), r(ly))?
+                                                        .add_point(r(mx), r(y))?;
+                                                }
+                                            }
+                                        }
+                                        w.add_point(r(x), r(y))?;
+                                        last = Some([x, y]);
+                                    }
+                                    Ok(w)
+                                })
+                        })?;
+                    }
+                }
+                PlotType::Scatter => {
+                    if name_exists {
+                        svg.single("circle", |w| {
+                            mark(w, interactive, "data-legend", i)?
+                                .with_attr("class", wr!("poloto{}fill", colori))?
+                                .attr("cx", r(legendx1 + padding / 30.0))?
+                                .attr("cy", r(legendy1))?
+                                .attr("r", r(padding / 30.0))
+                        })?;
+                    }
+
+                    svg.elem("g", |w| {
+                        let g = w.write(|w| {
+                            mark(clip_path(w, clip, &clip_id)?, interactive, "data-series", i)?
+                                .with_attr("class", wr!("poloto{}fill", colori))
+                        })?;
+
+                        for [x, y] in it.filter(is_finite) {
+                            let tooltip = tooltips.as_ref().map(|t| (t, current.get().1));
+                            single_with_tooltip(g, "circle", tooltip, |w| {
+                                //TODO use a g element!!!!
+                                w.attr("cx", r(x))?
+                                    .attr("cy", r(y))?
+                                    .attr("r", r(padding / 30.0))
+                            })?;
+                            label(current.get(), [x, y], false);
+                        }
+                        Ok(g)
                     })?;
                 }
+                PlotType::Histo => {
+                    if name_exists {
+                        svg.single("rect", |w| {
+                            mark(w, interactive, "data-legend", i)?
+                                .with_attr("class", wr!("poloto{}fill", colori))?
+                                .attr("x", r(legendx1))?
+                                .attr("y", r(legendy1 - padding / 30.0))?
+                                .attr("width", r(padding / 3.0))?
+                                .attr("height", r(padding / 20.0))?
+                                .attr("rx", r(padding / 30.0))?
+                                .attr("ry", r(padding / 30.0))
+                        })?;
+                    }
 
-                svg.single("polyline", |w| {
-                    w.with_attr("class", wr!("poloto{}stroke", colori))?
-                        .attr("fill", "none")?
-                        .attr("stroke", "black")?
-                        .points_data(|w| {
-                            for [x, y] in it {
-                                w.add_point(x, y)?;
+                    svg.elem("g", |w| {
+                        let g = w.write(|w| {
+                            mark(clip_path(w, clip, &clip_id)?, interactive, "data-series", i)?
+                                .with_attr("class", wr!("poloto{}fill", colori))
+                        })?;
+
+                        let mut base_it = base_it;
+                        let it = it
+                            .map(|a| {
+                                let by = match &mut base_it {
+                                    Some(base_it) => {
+                                        base_it.next().map(|[_, y]| y).unwrap_or(basey)
+                                    }
+                                    None => basey,
+                                };
+                                (a, by)
+                            })
+                            .filter(|(a, by)| is_finite(a) && by.is_finite());
+
+                        let mut last: Option<(f64, f64, f64)> = None;
+                        //The index and data point of the last point.
+                        let mut data = (0, [0.0; 2]);
+                        for ([x, y], by) in it {
+                            if let Some((lx, ly, lby)) = last {
+                                let width = (padding * 0.02).max((x - lx).abs() - (padding * 0.02));
+
+                                //If the x axis is inverted, the bar extends to the left of its point.
+                                let left = if x < lx { lx - width } else { lx };
+
+                                let tooltip = tooltips.as_ref().map(|t| (t, data.1));
+                                single_with_tooltip(g, "rect", tooltip, |w| {
+                                    w.attr("x", r(left))?
+                                        .attr("y", r(ly.min(lby)))?
+                                        .attr("width", r(width))?
+                                        .attr("height", r((lby - ly).abs()))
+                                })?;
+
+                                //The label goes past the end of the bar away from its base.
+                                label(data, [left + width / 2.0, ly], ly > lby);
                             }
-                            Ok(w)
-                        })
-                })?;
-            }
-            PlotType::Scatter => {
-                if name_exists {
-                    svg.single("circle", |w| {
-                        w.with_attr("class", wr!("poloto{}fill", colori))?
-                            .attr("cx", legendx1 + padding / 30.0)?
-                            .attr("cy", legendy1)?
-                            .attr("r", padding / 30.0)
+                            last = Some((x, y, by));
+                            data = current.get();
+                        }
+
+                        Ok(g)
                     })?;
                 }
+                PlotType::LineFill => {
+                    if name_exists {
+                        svg.single("rect", |w| {
+                            mark(w, interactive, "data-legend", i)?
+                                .with_attr("class", wr!("poloto{}fill", colori))?
+                                .attr("x", r(legendx1))?
+                                .attr("y", r(legendy1 - padding / 30.0))?
+                                .attr("width", r(padding / 3.0))?
+                                .attr("height", r(padding / 20.0))?
+                                .attr("rx", r(padding / 30.0))?
+                                .attr("ry", r(padding / 30.0))
+                        })?;
+                    }
+                    svg.single("path", |w| {
+                        mark(clip_path(w, clip, &clip_id)?, interactive, "data-series", i)?
+                            .with_attr("class", wr!("poloto{}fill", colori))?
+                            .path_data(|data| {
+                                use tagger::svg::PathCommand::*;
 
-                svg.elem("g", |w| {
-                    let g = w.write(|w| w.with_attr("class", wr!("poloto{}fill", colori)))?;
+                                //Points that are not finite leave a gap in the fill.
+                                //Each run of finite points is drawn as its own subpath.
+
+                                if let Some(base_it) = base_it {
+                                    let both_finite = |(a, b): &([f64; 2], [f64; 2])| {
+                                        is_finite(a) && is_finite(b)
+                                    };
+                                    let mut it = it.zip(base_it).peekable();
+                                    loop {
+                                        while it.next_if(|a| !both_finite(a)).is_some() {}
+                                        if it.peek().is_none() {
+                                            break;
+                                        }
+
+                                        //Trace the plot forward, then the base series backward.
+                                        let mut base = Vec::new();
+                                        while let Some(([x, y], b)) = it.next_if(both_finite) {
+                                            if base.is_empty() {
+                                                data.draw(M(r(x), r(y)))?;
+                                            } else {
+                                                data.draw(L(r(x), r(y)))?;
+                                            }
+                                            base.push(b);
+                                        }
+                                        for [x, y] in base.into_iter().rev() {
+                                            data.draw(L(r(x), r(y)))?;
+                                        }
+                                        data.draw_z()?;
+                                    }
+                                    return Ok(data);
+                                }
+
+                                let mut it = it.peekable();
+                                while it.next_if(|a| !is_finite(a)).is_some() {}
+
+                                //The first and last run extend to the edges of the x axis.
+                                let mut startx = xscale.start;
+                                while let Some(&[firstx, _]) = it.peek() {
+                                    data.draw(M(r(startx), r(basey)))?;
 
-                    for [x, y] in it {
-                        g.single("circle", |w| {
-                            //TODO use a g element!!!!
-                            w.attr("cx", x)?.attr("cy", y)?.attr("r", padding / 30.0)
+                                    let mut lastx = firstx;
+                                    while let Some([x, y]) = it.next_if(is_finite) {
+                                        data.draw(L(r(x), r(y)))?;
+                                        lastx = x;
+                                    }
+
+                                    while it.next_if(|a| !is_finite(a)).is_some() {}
+                                    let endx = match it.peek() {
+                                        Some(&[x, _]) => {
+                                            startx = x;
+                                            lastx
+                                        }
+                                        None => xscale.end,
+                                    };
+
+                                    data.draw(L(r(endx), r(basey)))?;
+                                    data.draw_z()?;
+                                }
+                                Ok(data)
+                            })
+                    })?;
+                }
+            }
+
+            let hits = hits.into_inner();
+            if let (Some(tooltips), false) = (tooltips, hits.is_empty()) {
+                svg.elem("g", |w| {
+                    let g = w.write(|w| {
+                        mark(w, interactive, "data-series", i)?
+                            .attr("fill", "black")?
+                            .attr("opacity", 0)
+                    })?;
+                    for (data, [x, y]) in hits.into_iter().filter(|(_, p)| is_finite(p)) {
+                        single_with_tooltip(g, "circle", Some((tooltips, data)), |w| {
+                            w.attr("cx", r(x))?
+                                .attr("cy", r(y))?
+                                .attr("r", r(padding / 15.0))
                         })?;
                     }
                     Ok(g)
                 })?;
             }
-            PlotType::Histo => {
-                if name_exists {
-                    svg.single("rect", |w| {
-                        w.with_attr("class", wr!("poloto{}fill", colori))?
-                            .attr("x", legendx1)?
-                            .attr("y", legendy1 - padding / 30.0)?
-                            .attr("width", padding / 3.0)?
-                            .attr("height", padding / 20.0)?
-                            .attr("rx", padding / 30.0)?
-                            .attr("ry", padding / 30.0)
-                    })?;
-                }
 
+            if let (Some(labels), false) = (labels, labelled.is_empty()) {
+                let gap = padding / 15.0;
                 svg.elem("g", |w| {
-                    let g = w.write(|w| w.with_attr("class", wr!("poloto{}fill", colori)))?;
-
-                    let mut last = None;
-                    for [x, y] in it {
-                        if let Some((lx, ly)) = last {
-                            g.single("rect", |w| {
-                                w.attr("x", lx)?
-                                    .attr("y", ly)?
-                                    .attr(
-                                        "width",
-                                        (padding * 0.02).max((x - lx) - (padding * 0.02)),
-                                    )?
-                                    .attr("height", height - paddingy - ly)
+                    let g = w.write(|w| {
+                        mark(w, interactive, "data-series", i)?
+                            .with_attr("class", wr!("poloto_text poloto{}label", i))?
+                            .attr("text-anchor", "middle")
+                    })?;
+                    for (index, data, [x, y], below) in labelled {
+                        let (y, baseline) = if below {
+                            (y + gap, "hanging")
+                        } else {
+                            (y - gap, "baseline")
+                        };
+                        g.elem("text", |writer| {
+                            let text = writer.write(|w| {
+                                w.attr("alignment-baseline", baseline)?
+                                    .attr("x", r(x))?
+                                    .attr("y", r(y))
                             })?;
-                        }
-                        last = Some((x, y))
+                            write!(text, "{}", moveable_format(|f| labels(index, data, f)))?;
+                            Ok(text)
+                        })?;
                     }
-
                     Ok(g)
                 })?;
             }
-            PlotType::LineFill => {
-                if name_exists {
-                    svg.single("rect", |w| {
-                        w.with_attr("class", wr!("poloto{}fill", colori))?
-                            .attr("x", legendx1)?
-                            .attr("y", legendy1 - padding / 30.0)?
-                            .attr("width", padding / 3.0)?
-                            .attr("height", padding / 20.0)?
-                            .attr("rx", padding / 30.0)?
-                            .attr("ry", padding / 30.0)
-                    })?;
-                }
-                svg.single("path", |w| {
-                    w.with_attr("class", wr!("poloto{}fill", colori))?
-                        .path_data(|data| {
-                            use tagger::svg::PathCommand::*;
-                            data.draw(M(padding, height - paddingy))?;
-
-                            for [x, y] in it {
-                                data.draw(L(x, y))?;
-                            }
+        }
 
-                            data.draw(L(width - padding, height - paddingy))?;
-                            data.draw_z()
-                        })
-                })?;
-            }
+        if let Some(e) = take_error(&mut **plots, base) {
+            return Err(e);
         }
     }
 
+    for a in annotations.iter().filter(|a| !a.is_background()) {
+        draw_annotation(
+            svg,
+            a,
+            xscale,
+            axis_scale(a.axis),
+            padding,
+            &clip_id,
+            precision,
+        )?;
+    }
+
     svg.elem("text", |writer| {
         let text = writer.write(|w| {
             w.attr("class", "poloto_text")?
                 .attr("alignment-baseline", "start")?
                 .attr("text-anchor", "middle")?
                 .attr("font-size", "x-large")?
-                .attr("x", width / 2.0)?
-                .attr("y", padding / 4.0)
+                .attr("x", r(width / 2.0))?
+                .attr("y", r(padding / 4.0))
         })?;
 
         write!(text, "{}", moveable_format(|f| names.write_title(f)))?;
@@ -392,8 +801,8 @@ pub(super) fn render<'a, 'x, T: Write>(
                 .attr("alignment-baseline", "start")?
                 .attr("text-anchor", "middle")?
                 .attr("font-size", "x-large")?
-                .attr("x", width / 2.0)?
-                .attr("y", height - padding / 8.)
+                .attr("x", r(width / 2.0))?
+                .attr("y", r(height - padding / 8.))
         })?;
         write!(text, "{}", moveable_format(|f| names.write_xname(f)))?;
 
@@ -408,27 +817,490 @@ pub(super) fn render<'a, 'x, T: Write>(
                 .attr("font-size", "x-large")?
                 .with_attr(
                     "transform",
-                    wr!("rotate(-90,{},{})", padding / 4.0, height / 2.0),
+                    wr!("rotate(-90,{},{})", r(padding / 4.0), r(height / 2.0)),
                 )?
-                .attr("x", padding / 4.0)?
-                .attr("y", height / 2.0)
+                .attr("x", r(padding / 4.0))?
+                .attr("y", r(height / 2.0))
         })?;
         write!(text, "{}", moveable_format(|f| names.write_yname(f)))?;
 
         Ok(text)
     })?;
 
+    if secondary.is_some() {
+        let x = yname2x;
+        svg.elem("text", |writer| {
+            let text = writer.write(|w| {
+                w.attr("class", "poloto_text")?
+                    .attr("alignment-baseline", "start")?
+                    .attr("text-anchor", "middle")?
+                    .attr("font-size", "x-large")?
+                    .with_attr("transform", wr!("rotate(90,{},{})", r(x), r(height / 2.0)))?
+                    .attr("x", r(x))?
+                    .attr("y", r(height / 2.0))
+            })?;
+            write!(text, "{}", moveable_format(|f| names.write_yname2(f)))?;
+
+            Ok(text)
+        })?;
+    }
+
     svg.single("path", |w| {
         w.attr("stroke", "black")?
             .attr("fill", "none")?
             .attr("class", "poloto_axis_lines")?
             .path_data(|p| {
                 use tagger::svg::PathCommand::*;
-                p.draw(M(padding, paddingy))?
-                    .draw(L(padding, height - paddingy))?
-                    .draw(L(width - padding, height - paddingy))
+                p.draw(M(r(padding), r(paddingy)))?
+                    .draw(L(r(padding), r(height - paddingy)))?
+                    .draw(L(r(width - paddingr), r(height - paddingy)))?;
+                if secondary.is_some() {
+                    p.draw(L(r(width - paddingr), r(paddingy)))?;
+                }
+                Ok(p)
             })
     })?;
 
     Ok(writer)
 }
+
+///Insert a range if the range is zero.
+pub(super) fn pad_empty_range([min, max]: [f64; 2]) -> [f64; 2] {
+    const EPSILON: f64 = f64::MIN_POSITIVE * 10.0;
+
+    if (max - min).abs() < EPSILON {
+        [min - 1.0, min + 1.0]
+    } else {
+        [min, max]
+    }
+}
+
+//Draw the ticks and tick labels of a y axis whose axis line is at `axisx`.
+//The primary axis has its labels on the left of the line, the secondary on the right.
+fn draw_yticks<T: Write>(
+    svg: &mut tagger::Element<T>,
+    axis: YAxis,
+    axisx: f64,
+    yscale: Scale,
+    labels: bool,
+    precision: Option<usize>,
+) -> fmt::Result {
+    let r = move |a: f64| util::round(a, precision);
+    let [miny, maxy] = yscale.range;
+    let padding = 150.0;
+    let paddingy = 100.0;
+
+    let ideal_num_ysteps = 10;
+
+    let textx_padding = padding * 0.1;
+
+    let (ystep_num, ystep, ystart_step) = util::find_good_step(ideal_num_ysteps, [miny, maxy]);
+
+    let distance_to_firsty = ystart_step - miny;
+
+    let (letter, anchor, tick_end, textx) = match axis {
+        YAxis::Primary => ("k", "end", axisx - padding * 0.04, axisx - textx_padding),
+        YAxis::Secondary => ("l", "start", axisx + padding * 0.04, axisx + textx_padding),
+    };
+
+    //step num is assured to be atleast 1.
+    let (extra, ystart_step) = if labels
+        && crate::util::determine_if_should_use_strat(
+            ystart_step,
+            ystart_step + ((ystep_num - 1) as f64) * ystep,
+            ystep,
+        )? {
+        svg.elem("text", |writer| {
+            let text = writer.write(|w| {
+                w.attr("class", "poloto_text")?
+                    .attr("alignment-baseline", "middle")?
+                    .attr(
+                        "text-anchor",
+                        match axis {
+                            YAxis::Primary => "start",
+                            YAxis::Secondary => "end",
+                        },
+                    )?
+                    .attr("x", r(axisx))?
+                    .attr("y", r(paddingy * 0.7))
+            })?;
+            write!(text, "Where {} = ", letter)?;
+
+            crate::util::interval_float(text, ystart_step, None)?; //Some(ystep)
+
+            Ok(text)
+        })?;
+
+        (letter, 0.0)
+    } else {
+        ("", ystart_step)
+    };
+
+    //Draw interval y text
+    for a in 0..ystep_num {
+        let p = (a as f64) * ystep;
+
+        let yy = yscale.offset(distance_to_firsty + p);
+
+        svg.single("line", |w| {
+            w.attr("class", "poloto_axis_lines")?
+                .attr("stroke", "black")?
+                .attr("x1", r(axisx))?
+                .attr("x2", r(tick_end))?
+                .attr("y1", r(yy))?
+                .attr("y2", r(yy))
+        })?;
+
+        if !labels {
+            continue;
+        }
+
+        svg.elem("text", |writer| {
+            let text = writer.write(|w| {
+                w.attr("class", "poloto_text")?
+                    .attr("alignment-baseline", "middle")?
+                    .attr("text-anchor", anchor)?
+                    .attr("x", r(textx))?
+                    .attr("y", r(yy))
+            })?;
+            if !extra.is_empty() {
+                write!(text, "{}+", extra)?;
+            }
+
+            util::interval_float(text, p + ystart_step, Some(ystep))?;
+            Ok(text)
+        })?;
+    }
+    Ok(())
+}
+
+//Draw an annotation, using `pos` to map data coordinates to svg coordinates.
+//Annotations at points that are not finite are left out.
+fn draw_annotation<T: Write>(
+    svg: &mut tagger::Element<T>,
+    annotation: &Annotation,
+    xscale: Scale,
+    yscale: Scale,
+    padding: f64,
+    clip_id: &str,
+    precision: Option<usize>,
+) -> fmt::Result {
+    let r = move |a: f64| util::round(a, precision);
+
+    let points = match annotation.kind {
+        AnnotationKind::Text { pos: a, .. } | AnnotationKind::Marker { pos: a } => [a, a],
+        AnnotationKind::Arrow { from, to, .. } => [from, to],
+        AnnotationKind::Rule { .. } | AnnotationKind::Span { .. } => {
+            return draw_reference(svg, annotation, xscale, yscale, padding, clip_id, precision);
+        }
+    };
+    if !points.iter().all(is_finite) {
+        return Ok(());
+    }
+    let pos = |[x, y]: [f64; 2]| [xscale.pos(x), yscale.pos(y)];
+    let [from, to] = [pos(points[0]), pos(points[1])];
+
+    svg.elem("g", |w| {
+        let g = w.write(|w| annotation_attrs(w, annotation, clip_id))?;
+
+        let text = match &annotation.kind {
+            AnnotationKind::Text { text, .. } | AnnotationKind::Arrow { text, .. } => text,
+            AnnotationKind::Rule { .. } | AnnotationKind::Span { .. } => unreachable!(),
+            AnnotationKind::Marker { .. } => {
+                g.single("circle", |w| {
+                    w.attr("fill", "none")?
+                        .attr("cx", r(to[0]))?
+                        .attr("cy", r(to[1]))?
+                        .attr("r", r(padding / 20.0))
+                })?;
+                return Ok(g);
+            }
+        };
+
+        //Text is centered on its point, unless an arrow leaves from it.
+        //Then it is placed on the side facing away from the arrow.
+        let mut align = ("middle", "middle");
+
+        if let AnnotationKind::Arrow { .. } = annotation.kind {
+            let [dx, dy] = [to[0] - from[0], to[1] - from[1]];
+            let length = dx.hypot(dy);
+            if length > 0.0 {
+                let [ux, uy] = [dx / length, dy / length];
+
+                align = if dx.abs() >= dy.abs() {
+                    (if dx > 0.0 { "end" } else { "start" }, "middle")
+                } else {
+                    ("middle", if dy > 0.0 { "baseline" } else { "hanging" })
+                };
+
+                //Leave a gap next to the text, and stop the line at the arrow head.
+                let gap = (padding / 30.0).min(length);
+                let head = (padding / 15.0).min(length);
+                let start = [from[0] + ux * gap, from[1] + uy * gap];
+                let base = [to[0] - ux * head, to[1] - uy * head];
+
+                if gap + head < length {
+                    g.single("line", |w| {
+                        w.attr("x1", r(start[0]))?
+                            .attr("y1", r(start[1]))?
+                            .attr("x2", r(base[0]))?
+                            .attr("y2", r(base[1]))
+                    })?;
+                }
+
+                let [nx, ny] = [-uy * head / 2.0, ux * head / 2.0];
+                g.single("path", |w| {
+                    w.path_data(|data| {
+                        use tagger::svg::PathCommand::*;
+                        data.draw(M(r(to[0]), r(to[1])))?
+                            .draw(L(r(base[0] + nx), r(base[1] + ny)))?
+                            .draw(L(r(base[0] - nx), r(base[1] - ny)))?
+                            .draw_z()
+                    })
+                })?;
+            }
+        }
+
+        let (anchor, baseline) = align;
+        g.elem("text", |writer| {
+            let t = writer.write(|w| {
+                w.attr("stroke", "none")?
+                    .attr("alignment-baseline", baseline)?
+                    .attr("text-anchor", anchor)?
+                    .attr("x", r(from[0]))?
+                    .attr("y", r(from[1]))
+            })?;
+            write!(t, "{}", text)?;
+            Ok(t)
+        })?;
+        Ok(g)
+    })?;
+    Ok(())
+}
+
+//Draw a reference line or span across the plot area.
+//Lines and spans at values that are not finite are left out.
+fn draw_reference<T: Write>(
+    svg: &mut tagger::Element<T>,
+    annotation: &Annotation,
+    xscale: Scale,
+    yscale: Scale,
+    padding: f64,
+    clip_id: &str,
+    precision: Option<usize>,
+) -> fmt::Result {
+    let r = move |a: f64| util::round(a, precision);
+
+    let (vertical, [a, b], label, span) = match annotation.kind {
+        AnnotationKind::Rule {
+            vertical,
+            value,
+            ref label,
+            ..
+        } => (vertical, [value, value], label, false),
+        AnnotationKind::Span {
+            vertical,
+            range,
+            ref label,
+            ..
+        } => (vertical, range, label, true),
+        _ => unreachable!(),
+    };
+    if !(a.is_finite() && b.is_finite()) {
+        return Ok(());
+    }
+
+    //The area covered in svg coordinates, as `[left,top,right,bottom]`.
+    let sort = |a: f64, b: f64| [a.min(b), a.max(b)];
+    let [left, right, top, bottom] = if vertical {
+        let [left, right] = sort(xscale.pos(a), xscale.pos(b));
+        let [top, bottom] = sort(yscale.start, yscale.end);
+        [left, right, top, bottom]
+    } else {
+        let [left, right] = sort(xscale.start, xscale.end);
+        let [top, bottom] = sort(yscale.pos(a), yscale.pos(b));
+        [left, right, top, bottom]
+    };
+
+    svg.elem("g", |w| {
+        let g = w.write(|w| annotation_attrs(w, annotation, clip_id))?;
+
+        if span {
+            g.single("rect", |w| {
+                w.attr("stroke", "none")?
+                    .attr("fill-opacity", 0.2)?
+                    .attr("x", r(left))?
+                    .attr("y", r(top))?
+                    .attr("width", r(right - left))?
+                    .attr("height", r(bottom - top))
+            })?;
+        } else {
+            g.single("line", |w| {
+                w.attr("stroke-dasharray", "8 4")?
+                    .attr("x1", r(left))?
+                    .attr("y1", r(top))?
+                    .attr("x2", r(right))?
+                    .attr("y2", r(bottom))
+            })?;
+        }
+
+        //Vertical labels go right of the top left corner, horizontal labels above the right end.
+        //If there is not enough room for the label inside the plot area, it goes on the other side.
+        let gap = padding / 30.0;
+        let room = padding / 2.0;
+        let (x, y, anchor, baseline) = if vertical {
+            if xscale.start.max(xscale.end) - left < room {
+                (left - gap, top + gap, "end", "hanging")
+            } else {
+                (left + gap, top + gap, "start", "hanging")
+            }
+        } else if top - yscale.start.min(yscale.end) < room / 5.0 {
+            (right - gap, top + gap, "end", "hanging")
+        } else {
+            (right - gap, top - gap, "end", "baseline")
+        };
+        g.elem("text", |writer| {
+            let t = writer.write(|w| {
+                w.attr("stroke", "none")?
+                    .attr("alignment-baseline", baseline)?
+                    .attr("text-anchor", anchor)?
+                    .attr("x", r(x))?
+                    .attr("y", r(y))
+            })?;
+            write!(t, "{}", label)?;
+            Ok(t)
+        })?;
+        Ok(g)
+    })?;
+    Ok(())
+}
+
+//The attributes shared by the group of every annotation.
+fn annotation_attrs<'w, W: WriteAttr>(
+    w: &'w mut W,
+    annotation: &Annotation,
+    clip_id: &str,
+) -> Result<&'w mut W, fmt::Error> {
+    clip_path(w, annotation.clip, clip_id)?
+        .attr("class", "poloto_annotation")?
+        .attr("stroke", "black")?
+        .attr("fill", "black")
+}
+
+//Write an element without children, or with a title child if there is a tooltip for it.
+fn single_with_tooltip<T: Write, F>(
+    g: &mut tagger::Element<T>,
+    tag: &str,
+    tooltip: Option<(&Tooltips, [f64; 2])>,
+    func: F,
+) -> fmt::Result
+where
+    for<'x, 'y> F: FnOnce(
+        &'x mut tagger::AttributeWriter<'y, T>,
+    ) -> Result<&'x mut tagger::AttributeWriter<'y, T>, fmt::Error>,
+{
+    match tooltip {
+        Some((tooltips, p)) => {
+            g.elem(tag, |w| {
+                let e = w.write(func)?;
+                e.elem_no_attr("title", |t| {
+                    write!(t, "{}", moveable_format(|f| tooltips(p, f)))?;
+                    Ok(t)
+                })?;
+                Ok(e)
+            })?;
+        }
+        None => {
+            g.single(tag, func)?;
+        }
+    }
+    Ok(())
+}
+
+//Mark an element as part of the plot at index `i`, for the script of the html output.
+//Legend entries are marked with `data-legend`, and what is drawn at data coordinates with `data-series`.
+fn mark<'w, W: WriteAttr>(
+    w: &'w mut W,
+    interactive: bool,
+    name: &str,
+    i: usize,
+) -> Result<&'w mut W, fmt::Error> {
+    if interactive {
+        w.attr(name, i)
+    } else {
+        Ok(w)
+    }
+}
+
+//Clip the element to the plot area if `clip` is true.
+fn clip_path<'w, W: WriteAttr>(
+    w: &'w mut W,
+    clip: bool,
+    id: &str,
+) -> Result<&'w mut W, fmt::Error> {
+    if clip {
+        w.with_attr("clip-path", wr!("url(#{})", id))
+    } else {
+        Ok(w)
+    }
+}
+
+//The error that ended iterating over a plot or its base series early, if there was one.
+pub(super) fn take_error(plots: &mut dyn PlotTrait, base: &mut Base) -> Option<Error> {
+    let base_error = match base {
+        Base::Series(base) => base.take_error(),
+        Base::Value { .. } => None,
+    };
+    plots.take_error().or(base_error)
+}
+
+pub(super) fn is_finite([x, y]: &[f64; 2]) -> bool {
+    x.is_finite() && y.is_finite()
+}
+
+pub(super) fn baseline_value(baseline: Baseline) -> Option<f64> {
+    match baseline {
+        Baseline::Bottom => None,
+        Baseline::Zero => Some(0.0),
+        Baseline::Constant(a) => Some(a),
+    }
+}
+
+///Maps data values along one axis to svg coordinates.
+#[derive(Copy, Clone)]
+pub(super) struct Scale {
+    range: [f64; 2],
+    //The svg coordinate the smallest value of the range is mapped to.
+    start: f64,
+    //The svg coordinate the biggest value of the range is mapped to.
+    end: f64,
+    scale: f64,
+}
+
+impl Scale {
+    //If inverted, the range is mapped from `end` to `start` instead.
+    pub(super) fn new(range: [f64; 2], [start, end]: [f64; 2], invert: bool) -> Scale {
+        let [start, end] = if invert { [end, start] } else { [start, end] };
+        Scale {
+            range,
+            start,
+            end,
+            scale: (end - start) / (range[1] - range[0]),
+        }
+    }
+
+    //Map a distance from the smallest value of the range.
+    fn offset(&self, distance: f64) -> f64 {
+        self.start + distance * self.scale
+    }
+
+    pub(super) fn pos(&self, a: f64) -> f64 {
+        self.offset(a - self.range[0])
+    }
+
+    //Restrict a svg coordinate to lie between `start` and `end`.
+    pub(super) fn clamp(&self, a: f64) -> f64 {
+        a.max(self.start.min(self.end))
+            .min(self.start.max(self.end))
+    }
+}
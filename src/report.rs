@@ -0,0 +1,173 @@
+//A html page with prose and plots, for reports like benchmark results.
+use super::*;
+use build::default_tags::*;
+use figure::Panel;
+
+enum Section<'a> {
+    Heading(Box<dyn Display + 'a>),
+    Paragraph(Box<dyn Display + 'a>),
+    Plot(Box<dyn Panel + 'a>),
+}
+
+///A single html page of headings, paragraphs and plots, with a button to switch
+///between a light and a dark theme.
+///
+///The svgs of the plots are embedded inline and share one stylesheet that uses css variables,
+///so they follow the theme. The header of each [`Plotter`] is ignored.
+///The page starts in the dark theme if the browser prefers it.
+///The title is escaped, but headings and paragraphs are written as is, so they can contain html.
+///
+/// # Example
+///
+/// ```
+/// let data=[
+///         [1.0f64,4.0],
+///         [2.0,5.0],
+///         [3.0,6.0]
+/// ];
+/// use poloto::prelude::*;
+/// let mut plotter = poloto::plot("insert","size","time");
+/// plotter.line("vec",data.iter().twice_iter());
+///
+/// let mut report = poloto::Report::new("benchmarks");
+/// report
+///     .heading("Insertion")
+///     .paragraph("Inserting gets slower as the collection grows.")
+///     .plot(plotter);
+/// let html = report.render_to_string().unwrap();
+/// ```
+pub struct Report<'a> {
+    title: Box<dyn Display + 'a>,
+    style: Box<dyn Display + 'a>,
+    sections: Vec<Section<'a>>,
+}
+
+impl<'a> Report<'a> {
+    ///Create an empty report whose plots are styled with
+    ///[`StyleBuilder::build_with_css_variables`](build::StyleBuilder::build_with_css_variables).
+    pub fn new(title: impl Display + 'a) -> Self {
+        Report {
+            title: Box::new(title),
+            style: Box::new(build::StyleBuilder::new().build_with_css_variables()),
+            sections: Vec::new(),
+        }
+    }
+
+    ///Replace the stylesheet shared by the plots.
+    ///
+    ///Use [`StyleBuilder::build_with_css_variables`](build::StyleBuilder::build_with_css_variables)
+    ///so the plots still follow the theme.
+    pub fn style(&mut self, style: impl Display + 'a) -> &mut Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    ///Add a heading after the existing sections.
+    pub fn heading(&mut self, text: impl Display + 'a) -> &mut Self {
+        self.sections.push(Section::Heading(Box::new(text)));
+        self
+    }
+
+    ///Add a paragraph after the existing sections.
+    pub fn paragraph(&mut self, text: impl Display + 'a) -> &mut Self {
+        self.sections.push(Section::Paragraph(Box::new(text)));
+        self
+    }
+
+    ///Add a plot after the existing sections.
+    pub fn plot<D: Names + 'a>(&mut self, plotter: Plotter<'a, D>) -> &mut Self {
+        self.sections.push(Section::Plot(Box::new(plotter)));
+        self
+    }
+
+    ///Render the page to a [`String`].
+    pub fn render_to_string(&mut self) -> Result<String, Error> {
+        let mut s = String::new();
+        self.render(&mut s)?;
+        Ok(s)
+    }
+
+    ///Render the page to a [`std::io::Write`] like a file, the same way as [`Plotter::render_io`].
+    pub fn render_io<T: std::io::Write>(&mut self, writer: T) -> Result<T, Error> {
+        render_io(writer, |w| self.render(w).map(|_| ()))
+    }
+
+    ///Render the page to the writer.
+    ///
+    ///Rendering again fails if one of the plots used up its data, see [`Plotter::render`].
+    pub fn render<T: fmt::Write>(&mut self, writer: T) -> Result<T, Error> {
+        for section in self.sections.iter() {
+            if let Section::Plot(panel) = section {
+                panel.check_not_consumed()?;
+            }
+        }
+
+        let mut root = tagger::Element::new(writer);
+        write!(
+            root,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n{}\n<style>{}</style>\n</head>\n<body>\n",
+            util::Escape(&self.title),
+            self.style,
+            THEME
+        )?;
+        write!(
+            root,
+            "<button type=\"button\" class=\"poloto_theme\">Switch theme</button>\n<h1>{}</h1>\n",
+            util::Escape(&self.title)
+        )?;
+
+        for section in self.sections.iter_mut() {
+            match section {
+                Section::Heading(text) => writeln!(root, "<h2>{}</h2>", text)?,
+                Section::Paragraph(text) => writeln!(root, "<p>{}</p>", text)?,
+                Section::Plot(panel) => {
+                    let bounds = panel.bounds()?;
+
+                    error::capture(|c| {
+                        root.elem("svg", |writer| {
+                            let svg = writer.write(|w| default_svg_attrs(w))?;
+                            c.hold(panel.draw(svg.get_writer(), bounds, Default::default()))?;
+                            Ok(svg)
+                        })
+                    })?;
+                    writeln!(root)?;
+                }
+            }
+        }
+
+        write!(root, "<script>{}</script>\n</body>\n</html>\n", SCRIPT)?;
+        Ok(root.into_writer())
+    }
+}
+
+//The page colors, and the colors of the plots in the dark theme.
+const THEME: &str = r###"
+body{font-family:"Arial";max-width:800px;margin:auto;padding:1em;background:white;color:black;}
+body.poloto_dark{background:#121212;color:#e0e0e0;}
+.poloto_theme{float:right;}
+svg.poloto{max-width:100%;height:auto;}
+body.poloto_dark .poloto{
+    --poloto_bg_color:#1e1e1e;
+    --poloto_fg_color:#e0e0e0;
+    --poloto_color0:#6fa8ff;
+    --poloto_color1:#ff6b6b;
+    --poloto_color2:#5ed16a;
+    --poloto_color3:gold;
+    --poloto_color4:aqua;
+    --poloto_color5:#d2996c;
+    --poloto_color6:lime;
+    --poloto_color7:#ff9f40;
+}
+"###;
+
+const SCRIPT: &str = r###"
+(function () {
+  var body = document.body;
+  if (window.matchMedia && window.matchMedia("(prefers-color-scheme: dark)").matches) {
+    body.classList.add("poloto_dark");
+  }
+  document.querySelector(".poloto_theme").addEventListener("click", function () {
+    body.classList.toggle("poloto_dark");
+  });
+})();
+"###;
@@ -0,0 +1,88 @@
+//Adaptive sampling of functions for Plotter::function and Plotter::parametric.
+
+//The number of evenly spaced intervals that are sampled before refining.
+const INITIAL_SAMPLES: usize = 64;
+
+//How many times an interval can be halved.
+const MAX_DEPTH: u32 = 10;
+
+//How far the middle of an interval can be from the straight line between its ends,
+//as a fraction of the size of the curve, before it is split.
+const TOLERANCE: f64 = 0.001;
+
+//Sample the curve from start to end.
+//Intervals where the curve is not close to a straight line are split in half until it is.
+pub(crate) fn adaptive([start, end]: [f64; 2], func: impl Fn(f64) -> [f64; 2]) -> Vec<[f64; 2]> {
+    let initial: Vec<(f64, [f64; 2])> = (0..=INITIAL_SAMPLES)
+        .map(|i| {
+            let t = start + (end - start) * (i as f64 / INITIAL_SAMPLES as f64);
+            (t, func(t))
+        })
+        .collect();
+
+    //The size of the curve is estimated from the evenly spaced samples.
+    let size = match crate::util::find_bounds(
+        initial
+            .iter()
+            .map(|&(_, a)| a)
+            .filter(|[x, y]| x.is_finite() && y.is_finite()),
+    ) {
+        Some([minx, maxx, miny, maxy]) => [nonzero(maxx - minx), nonzero(maxy - miny)],
+        None => [1.0, 1.0],
+    };
+
+    let mut sampler = Sampler {
+        func,
+        size,
+        points: vec![initial[0].1],
+    };
+    for w in initial.windows(2) {
+        sampler.refine(w[0], w[1], 0);
+    }
+    sampler.points
+}
+
+fn nonzero(a: f64) -> f64 {
+    if a > 0.0 {
+        a
+    } else {
+        1.0
+    }
+}
+
+struct Sampler<F> {
+    func: F,
+    size: [f64; 2],
+    points: Vec<[f64; 2]>,
+}
+
+impl<F: Fn(f64) -> [f64; 2]> Sampler<F> {
+    //Add the points after `a`, up to and including `b`.
+    fn refine(&mut self, a: (f64, [f64; 2]), b: (f64, [f64; 2]), depth: u32) {
+        if depth < MAX_DEPTH {
+            let t = (a.0 + b.0) / 2.0;
+            let mid = (t, (self.func)(t));
+            if self.should_split(a.1, mid.1, b.1) {
+                self.refine(a, mid, depth + 1);
+                self.refine(mid, b, depth + 1);
+                return;
+            }
+        }
+        self.points.push(b.1);
+    }
+
+    fn should_split(&self, a: [f64; 2], mid: [f64; 2], b: [f64; 2]) -> bool {
+        let finite = |[x, y]: [f64; 2]| x.is_finite() && y.is_finite();
+
+        //Narrow down where the curve stops being finite.
+        match (finite(a), finite(mid), finite(b)) {
+            (true, true, true) => {}
+            (false, false, false) => return false,
+            _ => return true,
+        }
+
+        let dx = (mid[0] - (a[0] + b[0]) / 2.0) / self.size[0];
+        let dy = (mid[1] - (a[1] + b[1]) / 2.0) / self.size[1];
+        dx.hypot(dy) > TOLERANCE
+    }
+}
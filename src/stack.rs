@@ -0,0 +1,97 @@
+use super::*;
+
+//The name, upper curve and lower curve of a layer.
+pub(crate) type Layer<'a> = (Box<dyn Display + 'a>, Vec<[f64; 2]>, Vec<[f64; 2]>);
+
+///A stacked area plot. Add it to a [`Plotter`] with [`Plotter::stacked_area`].
+///
+///Each layer is stacked on top of the layers added before it.
+///The layers are paired up by index, so they should share the same x positions.
+///The x positions of the first layer are used, and the stack is as long as its shortest layer.
+///
+///Since every layer has to be known before any of them can be accumulated,
+///the layers are stored in a Vec as they are added.
+pub struct StackedArea<'a> {
+    names: Vec<Box<dyn Display + 'a>>,
+    layers: Vec<Vec<[f64; 2]>>,
+    normalized: bool,
+}
+
+impl<'a> Default for StackedArea<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> StackedArea<'a> {
+    pub fn new() -> Self {
+        StackedArea {
+            names: Vec::new(),
+            layers: Vec::new(),
+            normalized: false,
+        }
+    }
+
+    ///Add a layer on top of the existing ones.
+    pub fn layer(
+        mut self,
+        name: impl Display + 'a,
+        plots: impl IntoIterator<Item = impl PlotPoint>,
+    ) -> Self {
+        self.names.push(Box::new(name));
+        self.layers
+            .push(plots.into_iter().map(|a| a.to_point()).collect());
+        self
+    }
+
+    ///Scale the layers so that at every x position they add up to 100.
+    pub fn normalized(mut self) -> Self {
+        self.normalized = true;
+        self
+    }
+
+    pub(crate) fn into_layers(self) -> Vec<Layer<'a>> {
+        let StackedArea {
+            names,
+            layers,
+            normalized,
+        } = self;
+
+        let len = layers.iter().map(|a| a.len()).min().unwrap_or(0);
+
+        let xs: Vec<f64> = match layers.first() {
+            Some(a) => a[..len].iter().map(|&[x, _]| x).collect(),
+            None => return Vec::new(),
+        };
+
+        let totals: Vec<f64> = (0..len)
+            .map(|i| layers.iter().map(|a| a[i][1]).sum())
+            .collect();
+
+        let mut lower: Vec<[f64; 2]> = xs.iter().map(|&x| [x, 0.0]).collect();
+
+        names
+            .into_iter()
+            .zip(layers)
+            .map(|(name, a)| {
+                let upper: Vec<[f64; 2]> = lower
+                    .iter()
+                    .zip(a.iter())
+                    .zip(totals.iter())
+                    .map(|((&[x, base], &[_, y]), &total)| {
+                        let y = if !normalized {
+                            y
+                        } else if total == 0.0 {
+                            0.0
+                        } else {
+                            y / total * 100.0
+                        };
+                        [x, base + y]
+                    })
+                    .collect();
+
+                (name, upper.clone(), core::mem::replace(&mut lower, upper))
+            })
+            .collect()
+    }
+}
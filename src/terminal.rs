@@ -0,0 +1,452 @@
+//Text output for looking at plots in a terminal, drawn with braille characters.
+use super::*;
+use render::{baseline_value, is_finite, pad_empty_range, take_error, Scale};
+
+//The ansi foreground colors closest to the default plot colors, in the same order.
+const ANSI_COLORS: [u8; NUM_COLORS] = [34, 31, 32, 33, 36, 35, 92, 91];
+
+//The bit of a braille character that sets each of its 2x4 dots, as `[row][column]`.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+//The rows that aren't part of the plot area: the title, the y axis names,
+//the x axis line, the x tick labels and the x axis name.
+const FIXED_ROWS: usize = 5;
+
+//A grid of braille characters. Each character holds 2x4 dots,
+//so a dot coordinate is twice the column and four times the row.
+struct Canvas {
+    columns: usize,
+    rows: usize,
+    //The dots that are set in each cell, and the index of the plot that set one last.
+    cells: Vec<(u8, Option<usize>)>,
+}
+
+impl Canvas {
+    fn new(columns: usize, rows: usize) -> Self {
+        Canvas {
+            columns,
+            rows,
+            cells: vec![(0, None); columns * rows],
+        }
+    }
+
+    fn dots(&self) -> [f64; 2] {
+        [(self.columns * 2) as f64, (self.rows * 4) as f64]
+    }
+
+    //Dots outside of the grid are ignored.
+    fn dot(&mut self, [x, y]: [f64; 2], plot: usize) {
+        let [x, y] = [x.round(), y.round()];
+        let [width, height] = self.dots();
+        if !(x >= 0.0 && y >= 0.0 && x < width && y < height) {
+            return;
+        }
+        let [x, y] = [x as usize, y as usize];
+        let cell = &mut self.cells[(y / 4) * self.columns + x / 2];
+        cell.0 |= BRAILLE_DOTS[y % 4][x % 2];
+        cell.1 = Some(plot);
+    }
+
+    fn line(&mut self, [ax, ay]: [f64; 2], [bx, by]: [f64; 2], plot: usize) {
+        let steps = (bx - ax).abs().max((by - ay).abs()).ceil().max(1.0);
+        for k in 0..=steps as usize {
+            let t = k as f64 / steps;
+            self.dot([ax + (bx - ax) * t, ay + (by - ay) * t], plot);
+        }
+    }
+
+    //Set every dot in the column at `x` between the two y coordinates.
+    fn column(&mut self, x: f64, [y1, y2]: [f64; 2], plot: usize) {
+        let (top, bottom) = (y1.min(y2).round(), y1.max(y2).round());
+        let mut y = top;
+        while y <= bottom {
+            self.dot([x, y], plot);
+            y += 1.0;
+        }
+    }
+
+    //Consecutive cells of the same plot share one color code.
+    fn write_row(&self, writer: &mut dyn fmt::Write, row: usize, color: bool) -> fmt::Result {
+        let cells = &self.cells[row * self.columns..(row + 1) * self.columns];
+        let mut start = 0;
+        while start < cells.len() {
+            let plot = if color { cells[start].1 } else { None };
+            let mut end = start + 1;
+            while end < cells.len() && (!color || cells[end].1 == plot) {
+                end += 1;
+            }
+            //The blank braille character keeps the width of the row the same in every font.
+            let run: String = cells[start..end]
+                .iter()
+                .map(|&(dots, _)| char::from_u32(0x2800 + dots as u32).unwrap())
+                .collect();
+            match plot {
+                Some(plot) => write_colored(writer, run, plot)?,
+                None => write!(writer, "{}", run)?,
+            }
+            start = end;
+        }
+        Ok(())
+    }
+}
+
+fn write_colored(writer: &mut dyn fmt::Write, a: impl Display, plot: usize) -> fmt::Result {
+    write!(
+        writer,
+        "\x1b[{}m{}\x1b[0m",
+        ANSI_COLORS[plot % NUM_COLORS],
+        a
+    )
+}
+
+//The fewest ticks to ask for. With fewer, the step can be bigger than the range,
+//leaving no tick inside of it.
+const MIN_TICKS: usize = 4;
+
+//The ticks of an axis, as the dot coordinate of each tick and its label.
+fn ticks(
+    range: [f64; 2],
+    ideal_num_steps: usize,
+    scale: Scale,
+) -> Result<Vec<(f64, String)>, Error> {
+    let (num, step, start) = util::find_good_step(ideal_num_steps, range);
+    let mut ticks = Vec::with_capacity(num);
+    for k in 0..num {
+        let value = start + step * k as f64;
+        let mut label = String::new();
+        util::interval_float(&mut label, value, Some(step))?;
+        ticks.push((scale.pos(value), label));
+    }
+    Ok(ticks)
+}
+
+//Write the text centered in a row of the given width.
+fn write_centered(writer: &mut dyn fmt::Write, text: &str, width: usize) -> fmt::Result {
+    let len = text.chars().count();
+    writeln!(writer, "{:1$}{2}", "", width.saturating_sub(len) / 2, text)
+}
+
+impl<'a, D: Names> Plotter<'a, D> {
+    /// Render the plot as text, using braille characters for the plot area.
+    ///
+    /// `[columns,rows]` is the size of the whole output in characters,
+    /// including the axes, tick labels and one row for each plot in the legend.
+    /// If `color` is true, each plot and its legend entry are wrapped in the
+    /// ansi color code of its index, picked to resemble the default svg colors.
+    ///
+    /// Line fills and histograms are filled in, and step plots are drawn with their steps.
+    /// Annotations, point labels and tooltips are left out.
+    /// Returns [`Error::InvalidData`] if the size is too small to fit a plot area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let data=[
+    ///         [1.0f64,4.0],
+    ///         [2.0,5.0],
+    ///         [3.0,6.0]
+    /// ];
+    /// use poloto::prelude::*;
+    /// let mut plotter = poloto::plot("title","x","y");
+    /// plotter.line("data",data.iter().twice_iter());
+    /// let text = plotter.render_terminal(String::new(), [60, 20], false).unwrap();
+    /// assert_eq!(text.lines().count(), 20);
+    /// ```
+    pub fn render_terminal<T: fmt::Write>(
+        &mut self,
+        mut writer: T,
+        [columns, rows]: [usize; 2],
+        color: bool,
+    ) -> Result<T, Error> {
+        self.check_not_consumed()?;
+
+        let Plotter {
+            names,
+            plots,
+            annotations,
+            options,
+            ..
+        } = self;
+        let options = *options;
+
+        let render::Bounds { primary, secondary } = render::find_bounds(plots, annotations)?;
+
+        let mut legend = Vec::new();
+        for (i, plot) in plots.iter().enumerate() {
+            let name = moveable_format(|f| plot.plots.write_name(f)).to_string();
+            if !name.is_empty() {
+                let marker = match plot.plot_type {
+                    PlotType::Line | PlotType::Step(_) => "──",
+                    PlotType::Scatter => "••",
+                    PlotType::Histo | PlotType::LineFill => "██",
+                };
+                legend.push((i, marker, name));
+            }
+        }
+
+        let height = match rows.checked_sub(FIXED_ROWS + legend.len()) {
+            Some(height) if height > 0 => height,
+            _ => {
+                return Err(Error::InvalidData(
+                    "terminal size is too small for the plot",
+                ))
+            }
+        };
+
+        let [minx, maxx, _, _] = match util::merge_bounds(primary, secondary) {
+            Some(bounds) => bounds,
+            None => {
+                //No finite points at all, so there is nothing to draw.
+                write_centered(
+                    &mut writer,
+                    &moveable_format(|f| names.write_title(f)).to_string(),
+                    columns,
+                )?;
+                return Ok(writer);
+            }
+        };
+        let [minx, maxx] = pad_empty_range([minx, maxx]);
+        let primary = primary.map(|[_, _, miny, maxy]| pad_empty_range([miny, maxy]));
+        let secondary = secondary.map(|[_, _, miny, maxy]| pad_empty_range([miny, maxy]));
+
+        //The y ticks only depend on the number of rows, so their labels are known before the width.
+        let ideal_num_ysteps = (height / 3).clamp(MIN_TICKS, 10);
+        let yscale = |range| Scale::new(range, [(height * 4 - 1) as f64, 0.0], options.invert_y);
+        let yticks = |range: Option<[f64; 2]>| match range {
+            Some(range) => ticks(range, ideal_num_ysteps, yscale(range)),
+            None => Ok(Vec::new()),
+        };
+        let (yticks1, yticks2) = (yticks(primary)?, yticks(secondary)?);
+        let label_width = |ticks: &[(f64, String)]| {
+            ticks
+                .iter()
+                .map(|(_, l)| l.chars().count())
+                .max()
+                .unwrap_or(0)
+        };
+        let (ywidth1, ywidth2) = (label_width(&yticks1), label_width(&yticks2));
+
+        //A space and the axis line on the left, and the same on the right for a secondary axis.
+        let right = if secondary.is_some() { ywidth2 + 2 } else { 0 };
+        let width = match columns.checked_sub(ywidth1 + 2 + right) {
+            Some(width) if width > 0 => width,
+            _ => {
+                return Err(Error::InvalidData(
+                    "terminal size is too small for the plot",
+                ))
+            }
+        };
+
+        let xscale = Scale::new(
+            [minx, maxx],
+            [0.0, (width * 2 - 1) as f64],
+            options.invert_x,
+        );
+        let ideal_num_xsteps = (width / 10).clamp(MIN_TICKS, 9);
+        let xticks = ticks([minx, maxx], ideal_num_xsteps, xscale)?;
+
+        let mut canvas = Canvas::new(width, height);
+        for (
+            i,
+            Plot {
+                plot_type,
+                axis,
+                base,
+                plots,
+                ..
+            },
+        ) in plots.iter_mut().enumerate()
+        {
+            let range = match axis {
+                YAxis::Primary => primary,
+                YAxis::Secondary => secondary,
+            };
+            //An axis always has a range if it has a plot.
+            let yscale = yscale(range.unwrap());
+
+            //Scoped so the plot iterators are dropped before checking for errors.
+            {
+                //The dot y coordinate that fills and bars extend towards,
+                //unless they extend towards another series.
+                let (basey, mut base_it) = match base {
+                    &mut Base::Value { baseline, .. } => {
+                        let bottom = yscale.pos(range.unwrap()[0]);
+                        let basey = match baseline_value(baseline) {
+                            Some(y) => yscale.clamp(yscale.pos(y)),
+                            None => bottom,
+                        };
+                        (basey, None)
+                    }
+                    Base::Series(base) => (0.0, Some(base.iter_second())),
+                };
+
+                //Each point with the y coordinate of its base.
+                let it = plots.iter_second().map(|[x, y]| {
+                    let by = match &mut base_it {
+                        Some(base_it) => base_it
+                            .next()
+                            .map(|[_, y]| yscale.pos(y))
+                            .unwrap_or(f64::NAN),
+                        None => basey,
+                    };
+                    ([xscale.pos(x), yscale.pos(y)], by)
+                });
+
+                let mut last: Option<([f64; 2], f64)> = None;
+                for (p, by) in it {
+                    //Points that are not finite leave a gap in lines and fills.
+                    if !is_finite(&p) {
+                        last = None;
+                        continue;
+                    }
+                    let [x, y] = p;
+                    match (*plot_type, last) {
+                        (PlotType::Scatter, _) => canvas.dot(p, i),
+                        (PlotType::Line, Some((l, _))) => canvas.line(l, p, i),
+                        (PlotType::Step(step), Some(([lx, ly], _))) => {
+                            let corners: &[[f64; 2]] = match step {
+                                StepKind::Before => &[[lx, ly], [lx, y], [x, y]],
+                                StepKind::After => &[[lx, ly], [x, ly], [x, y]],
+                                StepKind::Mid => {
+                                    let mx = (lx + x) / 2.0;
+                                    &[[lx, ly], [mx, ly], [mx, y], [x, y]]
+                                }
+                            };
+                            for w in corners.windows(2) {
+                                canvas.line(w[0], w[1], i);
+                            }
+                        }
+                        (PlotType::Histo, Some(([lx, ly], lby))) if lby.is_finite() => {
+                            //The last dot column before the next bar is left empty.
+                            let (start, end) = (lx.min(x).round(), lx.max(x).round());
+                            let end = (end - 1.0).max(start);
+                            let mut cx = start;
+                            while cx <= end {
+                                canvas.column(cx, [ly, lby], i);
+                                cx += 1.0;
+                            }
+                        }
+                        (PlotType::LineFill, Some(([lx, ly], lby)))
+                            if lby.is_finite() && by.is_finite() =>
+                        {
+                            let (start, end) = (lx.min(x).round(), lx.max(x).round());
+                            let mut cx = start;
+                            while cx <= end {
+                                let t = if x == lx { 0.0 } else { (cx - lx) / (x - lx) };
+                                let t = t.clamp(0.0, 1.0);
+                                canvas.column(cx, [ly + (y - ly) * t, lby + (by - lby) * t], i);
+                                cx += 1.0;
+                            }
+                        }
+                        (PlotType::Line | PlotType::Step(_), None) => canvas.dot(p, i),
+                        _ => {}
+                    }
+                    last = Some((p, by));
+                }
+            }
+
+            if let Some(e) = take_error(&mut **plots, base) {
+                return Err(e);
+            }
+        }
+
+        //Find the row or column of the cell each tick is in.
+        let tick_cells = |ticks: &[(f64, String)], dots: f64| -> Vec<(usize, String)> {
+            ticks
+                .iter()
+                .map(|(pos, label)| ((pos.round() / dots) as usize, label.clone()))
+                .collect()
+        };
+        let (yticks1, yticks2) = (tick_cells(&yticks1, 4.0), tick_cells(&yticks2, 4.0));
+        //The labels are laid out from left to right, but an inverted axis has its ticks from right to left.
+        let mut xticks = tick_cells(&xticks, 2.0);
+        xticks.sort_by_key(|&(cell, _)| cell);
+        let find = |ticks: &[(usize, String)], cell: usize| {
+            ticks
+                .iter()
+                .find(|(c, _)| *c == cell)
+                .map(|(_, l)| l.clone())
+        };
+
+        let total = ywidth1 + 2 + width + right;
+        write_centered(
+            &mut writer,
+            &moveable_format(|f| names.write_title(f)).to_string(),
+            total,
+        )?;
+
+        let yname = moveable_format(|f| names.write_yname(f)).to_string();
+        let yname2 = moveable_format(|f| names.write_yname2(f)).to_string();
+        //The secondary y axis name is right aligned above its axis.
+        if yname2.is_empty() {
+            writeln!(writer, "{}", yname)?;
+        } else {
+            let gap = total.saturating_sub(yname.chars().count() + yname2.chars().count());
+            writeln!(writer, "{}{:2$}{3}", yname, "", gap, yname2)?;
+        }
+
+        for row in 0..height {
+            let (label, axis) = match find(&yticks1, row) {
+                Some(label) => (label, '┤'),
+                None => (String::new(), '│'),
+            };
+            write!(writer, "{:>1$} {2}", label, ywidth1, axis)?;
+            canvas.write_row(&mut writer, row, color)?;
+            if secondary.is_some() {
+                match find(&yticks2, row) {
+                    Some(label) => write!(writer, "├ {}", label)?,
+                    None => write!(writer, "│")?,
+                }
+            }
+            writeln!(writer)?;
+        }
+
+        write!(writer, "{:1$} └", "", ywidth1)?;
+        for column in 0..width {
+            let c = if find(&xticks, column).is_some() {
+                '┬'
+            } else {
+                '─'
+            };
+            write!(writer, "{}", c)?;
+        }
+        if secondary.is_some() {
+            write!(writer, "┘")?;
+        }
+        writeln!(writer)?;
+
+        //Each label is centered under its tick, and is left out if it would overlap the previous one.
+        let mut line = String::new();
+        let mut len = 0;
+        let offset = ywidth1 + 2;
+        for (cell, label) in xticks.iter() {
+            let label_len = label.chars().count();
+            let start = (offset + cell).saturating_sub(label_len / 2);
+            let gap = if len == 0 { 0 } else { 1 };
+            if start >= len + gap && start + label_len <= total {
+                line.push_str(&" ".repeat(start - len));
+                line.push_str(label);
+                len = start + label_len;
+            }
+        }
+        writeln!(writer, "{}", line)?;
+
+        write_centered(
+            &mut writer,
+            &moveable_format(|f| names.write_xname(f)).to_string(),
+            total,
+        )?;
+
+        for (i, marker, name) in legend {
+            write!(writer, "  ")?;
+            if color {
+                write_colored(&mut writer, marker, i)?;
+            } else {
+                write!(writer, "{}", marker)?;
+            }
+            writeln!(writer, " {}", name)?;
+        }
+
+        Ok(writer)
+    }
+}
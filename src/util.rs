@@ -4,14 +4,14 @@
 //size of each interval
 //first interval location.
 pub fn find_good_step(num_steps: usize, range_all: [f64; 2]) -> (usize, f64, f64) {
-    let range_all = [range_all[0] as f64, range_all[1] as f64];
+    let range_all = [range_all[0], range_all[1]];
     let range = range_all[1] - range_all[0];
 
     //https://stackoverflow.com/questions/237220/tickmark-algorithm-for-a-graph-axis
 
     let rough_step = range / (num_steps - 1) as f64;
 
-    let step_power = 10.0f64.powf(-rough_step.abs().log10().floor()) as f64;
+    let step_power = 10.0f64.powf(-rough_step.abs().log10().floor());
     let normalized_step = rough_step * step_power;
 
     let good_steps = [1.0, 2.0, 5.0, 10.0];
@@ -57,7 +57,7 @@ pub fn find_good_step(num_steps: usize, range_all: [f64; 2]) -> (usize, f64, f64
     //necessarily true.
     //assert!(start_step + step * ((num_step - 1) as f64) <= range_all[1]);
 
-    (num_step, step as f64, start_step as f64)
+    (num_step, step, start_step)
 }
 
 use core::fmt;
@@ -106,6 +106,15 @@ pub fn determine_if_should_use_strat(start: f64, end: f64, step: f64) -> Result<
 
 const SCIENCE: usize = 4;
 
+//Write a value without rounding it, in scientific notation if it is very big or very small.
+pub fn write_exact<T: fmt::Write>(fm: &mut T, a: f64) -> fmt::Result {
+    if a != 0.0 && a.abs().log10().floor().abs() > SCIENCE as f64 {
+        write!(fm, "{:e}", a)
+    } else {
+        write!(fm, "{}", a)
+    }
+}
+
 /// The step amount dictates the precision we need to show at each interval
 /// in order to capture the changes from each step
 pub fn interval_float<T: fmt::Write>(fm: &mut T, a: f64, step: Option<f64>) -> fmt::Result {
@@ -152,3 +161,62 @@ pub fn find_bounds<K: PartialOrd + Copy>(it: impl IntoIterator<Item = [K; 2]>) -
         None
     }
 }
+
+///Combine two bounds returned by [`find_bounds`] into one that contains both.
+pub fn merge_bounds(a: Option<[f64; 4]>, b: Option<[f64; 4]>) -> Option<[f64; 4]> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some([
+            a[0].min(b[0]),
+            a[1].max(b[1]),
+            a[2].min(b[2]),
+            a[3].max(b[3]),
+        ]),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+//Round to the given number of decimal places, if there is one.
+//The rounded number is printed without the digits that were rounded away.
+pub fn round(a: f64, precision: Option<usize>) -> f64 {
+    match precision {
+        //An f64 has fewer significant decimal digits than this anyway.
+        Some(p) if p < 17 => {
+            let m = 10f64.powi(p as i32);
+            let b = (a * m).round() / m;
+            if b.is_finite() {
+                //Adding zero turns -0 into 0.
+                b + 0.0
+            } else {
+                a
+            }
+        }
+        _ => a,
+    }
+}
+
+//Display the inner value with the characters that are special in html escaped.
+pub struct Escape<T>(pub T);
+
+impl<T: fmt::Display> fmt::Display for Escape<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        struct Escaper<'a, 'b>(&'a mut fmt::Formatter<'b>);
+        impl fmt::Write for Escaper<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for c in s.chars() {
+                    match c {
+                        '<' => self.0.write_str("&lt;")?,
+                        '>' => self.0.write_str("&gt;")?,
+                        '&' => self.0.write_str("&amp;")?,
+                        '"' => self.0.write_str("&quot;")?,
+                        '\'' => self.0.write_str("&#39;")?,
+                        c => self.0.write_char(c)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+        use fmt::Write;
+        write!(Escaper(f), "{}", self.0)
+    }
+}
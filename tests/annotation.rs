@@ -0,0 +1,59 @@
+mod common;
+use common::{annotations, plotter};
+use poloto::prelude::*;
+
+#[test]
+fn annotations_are_drawn_at_data_coordinates() {
+    let mut plotter = plotter();
+    plotter.text([5.0, 5.0], "middle");
+    plotter.marker([0.0, 10.0]);
+    let s = plotter.render_to_string().unwrap();
+
+    let a = annotations(&s);
+    assert_eq!(a.len(), 2);
+
+    //The plot area goes from 150 to 650 horizontally, and from 400 to 100 vertically.
+    assert!(a[0].contains("x=\"400\" y=\"250\">middle"));
+    assert!(a[1].contains("<circle fill=\"none\" cx=\"150\" cy=\"100\""));
+}
+
+#[test]
+fn arrow_points_away_from_text() {
+    let mut plotter = plotter();
+    plotter.arrow([0.0, 5.0], [10.0, 5.0], "right");
+    let s = plotter.render_to_string().unwrap();
+
+    let a = annotations(&s);
+    assert_eq!(a.len(), 1);
+    assert!(a[0].contains("<line x1=\"155\" y1=\"250\" x2=\"640\" y2=\"250\"/>"));
+    assert!(a[0].contains("M 650 250"));
+    assert!(a[0].contains("text-anchor=\"end\" x=\"150\" y=\"250\">right"));
+}
+
+#[test]
+fn annotations_follow_clip_and_axis() {
+    let mut plotter = plotter();
+    plotter.text([5.0, 5.0], "clipped");
+    plotter.clip(false);
+    plotter.y_axis(poloto::YAxis::Secondary);
+    plotter.line("other", [[0.0, 0.0], [10.0, 100.0]].iter().twice_iter());
+    plotter.text([5.0, 50.0], "unclipped");
+    let s = plotter.render_to_string().unwrap();
+
+    let a = annotations(&s);
+    assert!(a[0].starts_with("clip-path="));
+    assert!(!a[1].contains("clip-path="));
+
+    //Both are halfway up their own axis.
+    assert!(a[0].contains("y=\"250\">clipped"));
+    assert!(a[1].contains("y=\"250\">unclipped"));
+}
+
+#[test]
+fn non_finite_annotations_are_left_out() {
+    let mut plotter = plotter();
+    plotter.text([f64::NAN, 5.0], "nan");
+    plotter.arrow([0.0, 5.0], [f64::INFINITY, 5.0], "inf");
+    let s = plotter.render_to_string().unwrap();
+    assert!(annotations(&s).is_empty());
+}
@@ -0,0 +1,103 @@
+mod common;
+use common::{attr, polylines, tags};
+use poloto::prelude::*;
+use poloto::Baseline;
+
+const DATA: [[f64; 2]; 3] = [[0.0, 1.0], [5.0, 2.0], [10.0, 3.0]];
+
+//The path data of the first line fill.
+fn fill(s: &str) -> &str {
+    let d = &s[s.find(" d=\" M").unwrap() + 4..];
+    &d[..d.find('"').unwrap()]
+}
+
+//The y and height of the bars of the first histogram.
+fn bars(s: &str) -> Vec<[f64; 2]> {
+    let g = &s[s.find("<g ").unwrap()..];
+    tags(&g[..g.find("</g>").unwrap()], "rect")
+        .into_iter()
+        .map(|t| [attr(t, "y"), attr(t, "height")])
+        .collect()
+}
+
+fn render(baseline: Baseline, include_in_bounds: bool) -> String {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.baseline(baseline, include_in_bounds);
+    plotter.line_fill("fill", DATA.iter().twice_iter());
+    plotter.histogram("histo", DATA.iter().twice_iter());
+    plotter.render_to_string().unwrap()
+}
+
+#[test]
+fn baseline_inside_the_range() {
+    let s = render(Baseline::Constant(2.0), false);
+    assert_eq!(
+        fill(&s),
+        " M 150 250 L 150 400 L 400 250 L 650 100 L 650 250Z"
+    );
+    assert_eq!(bars(&s), vec![[250.0, 150.0], [250.0, 0.0]]);
+}
+
+#[test]
+fn baseline_outside_the_range_is_clamped() {
+    let s = render(Baseline::Zero, false);
+    assert_eq!(
+        fill(&s),
+        " M 150 400 L 150 400 L 400 250 L 650 100 L 650 400Z"
+    );
+    assert_eq!(bars(&s), vec![[400.0, 0.0], [250.0, 150.0]]);
+
+    let s = render(Baseline::Constant(10.0), false);
+    assert_eq!(
+        fill(&s),
+        " M 150 100 L 150 400 L 400 250 L 650 100 L 650 100Z"
+    );
+    assert_eq!(bars(&s), vec![[100.0, 300.0], [100.0, 150.0]]);
+}
+
+#[test]
+fn baseline_included_in_bounds_widens_the_range() {
+    let s = render(Baseline::Zero, true);
+    assert_eq!(
+        fill(&s),
+        " M 150 400 L 150 300 L 400 200 L 650 100 L 650 400Z"
+    );
+    assert_eq!(bars(&s), vec![[300.0, 100.0], [200.0, 200.0]]);
+
+    let s = render(Baseline::Constant(5.0), true);
+    assert_eq!(
+        fill(&s),
+        " M 150 100 L 150 400 L 400 325 L 650 250 L 650 100Z"
+    );
+}
+
+#[test]
+fn baseline_does_not_widen_lines() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.baseline(Baseline::Zero, true);
+    plotter.line("line", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(
+        polylines(&s),
+        vec![vec![[150.0, 400.0], [400.0, 250.0], [650.0, 100.0]]]
+    );
+}
+
+#[test]
+fn fill_between_two_series() {
+    let below = || DATA.iter().map(|&[x, y]| [x, y - 2.0]).twice_iter();
+
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line_fill_between("fill", DATA.iter().twice_iter(), below());
+    let s = plotter.render_to_string().unwrap();
+    //The bounds include the base series, so the y axis goes from -1 to 3.
+    assert_eq!(
+        fill(&s),
+        " M 150 250 L 400 175 L 650 100 L 650 250 L 400 325 L 150 400Z"
+    );
+
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.histogram_between("histo", DATA.iter().twice_iter(), below());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(bars(&s), vec![[250.0, 150.0], [175.0, 150.0]]);
+}
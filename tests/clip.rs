@@ -0,0 +1,59 @@
+use poloto::prelude::*;
+
+const DATA: [[f64; 2]; 3] = [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+
+#[test]
+fn plots_are_clipped_by_default() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("line", DATA.iter().twice_iter());
+    plotter.scatter("scatter", DATA.iter().twice_iter());
+    plotter.histogram("histo", DATA.iter().twice_iter());
+    plotter.line_fill("fill", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+
+    assert_eq!(s.matches("<clipPath id=\"poloto_clip_").count(), 1);
+    assert_eq!(s.matches("clip-path=\"url(#poloto_clip_").count(), 4);
+}
+
+#[test]
+fn clipping_can_be_disabled() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("clipped", DATA.iter().twice_iter());
+    plotter.clip(false);
+    plotter.line("not clipped", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(s.matches("clip-path=").count(), 1);
+
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.clip(false);
+    plotter.line("not clipped", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert!(!s.contains("clipPath") && !s.contains("clip-path"));
+}
+
+//The ids of the clip paths in the svg.
+fn clip_ids(s: &str) -> Vec<&str> {
+    s.split("<clipPath id=\"")
+        .skip(1)
+        .map(|a| &a[..a.find('"').unwrap()])
+        .collect()
+}
+
+#[test]
+fn plots_on_one_page_have_different_clip_ids() {
+    let plotter = || {
+        let mut plotter = poloto::plot("title", "x", "y");
+        plotter.line("line", DATA.iter().twice_iter());
+        plotter
+    };
+    let mut report = poloto::Report::new("report");
+    report.plot(plotter()).plot(plotter());
+    let s = report.render_to_string().unwrap();
+
+    let ids = clip_ids(&s);
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+    for id in ids {
+        assert_eq!(s.matches(&format!("url(#{})", id)).count(), 1);
+    }
+}
@@ -0,0 +1,64 @@
+//Helpers shared by the integration tests. Each test file only uses some of them.
+#![allow(dead_code)]
+
+use poloto::prelude::*;
+
+//A plotter with one line from (0,0) to (10,10), to add annotations to.
+pub fn plotter<'a>() -> poloto::Plotter<'a, impl poloto::build::Names> {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("data", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter
+}
+
+//The contents of every annotation group in the svg.
+pub fn annotations(s: &str) -> Vec<&str> {
+    s.split("<g ")
+        .filter(|g| g.contains("class=\"poloto_annotation\""))
+        .map(|g| &g[..g.find("</g>").unwrap()])
+        .collect()
+}
+
+//The points of every polyline in the svg, in svg coordinates.
+pub fn polylines(s: &str) -> Vec<Vec<[f64; 2]>> {
+    s.split("<polyline")
+        .skip(1)
+        .map(|a| {
+            let start = a.find("points=\"").unwrap() + "points=\"".len();
+            let end = start + a[start..].find('"').unwrap();
+            a[start..end]
+                .split_whitespace()
+                .map(|p| {
+                    let mut i = p.split(',').map(|v| v.parse().unwrap());
+                    [i.next().unwrap(), i.next().unwrap()]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+//Every start tag with the given name in the svg, up to its closing `>`.
+pub fn tags<'a>(s: &'a str, name: &str) -> Vec<&'a str> {
+    s.split(&format!("<{}", name))
+        .skip(1)
+        .filter(|t| t.starts_with(' '))
+        .map(|t| &t[..t.find('>').unwrap()])
+        .collect()
+}
+
+//The value of a number attribute of a tag.
+pub fn attr(tag: &str, name: &str) -> f64 {
+    let v = &tag[tag.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3..];
+    v[..v.find('"').unwrap()].parse().unwrap()
+}
+
+//The svg with the number of every clip path id left out,
+//to compare the output of different plotters.
+pub fn without_ids(s: &str) -> String {
+    let mut parts = s.split("poloto_clip_");
+    let mut out = parts.next().unwrap().to_string();
+    for part in parts {
+        out.push_str("poloto_clip_");
+        out.push_str(part.trim_start_matches(|c: char| c.is_ascii_digit()));
+    }
+    out
+}
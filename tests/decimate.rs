@@ -0,0 +1,76 @@
+mod common;
+use common::polylines;
+use poloto::prelude::*;
+use poloto::Decimation;
+
+//The number of points of every polyline in the svg.
+fn polyline_lens(s: &str) -> Vec<usize> {
+    polylines(s).iter().map(|a| a.len()).collect()
+}
+
+fn render(decimation: Decimation, data: Vec<[f64; 2]>) -> String {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.decimation(decimation);
+    plotter.line("", data.twice_iter());
+    plotter.render_to_string().unwrap()
+}
+
+fn noise(n: usize) -> Vec<[f64; 2]> {
+    (0..n)
+        .map(|x| [x as f64, ((x * 7919) % 1000) as f64])
+        .collect()
+}
+
+#[test]
+fn none_keeps_every_point() {
+    assert_eq!(
+        polyline_lens(&render(Decimation::None, noise(1000))),
+        [1000]
+    );
+}
+
+#[test]
+fn lttb_reduces_to_target() {
+    assert_eq!(
+        polyline_lens(&render(Decimation::Lttb(200), noise(100_000))),
+        [200]
+    );
+}
+
+#[test]
+fn min_max_keeps_spikes() {
+    let mut data: Vec<[f64; 2]> = (0..100_000).map(|x| [x as f64, 1.0]).collect();
+    data[31_337][1] = 1000.0;
+    data[77_777][1] = -1000.0;
+
+    let s = render(Decimation::MinMax(100), data.clone());
+    let lens = polyline_lens(&s);
+    assert!(lens[0] <= 102, "{:?}", lens);
+
+    //The y axis range is the same, so the extremes are drawn in the same place.
+    let full = render(Decimation::None, data);
+    let extremes = |s: &str| {
+        polylines(s)[0]
+            .iter()
+            .fold([f64::INFINITY, f64::NEG_INFINITY], |[lo, hi], [_, y]| {
+                [lo.min(*y), hi.max(*y)]
+            })
+    };
+    assert_eq!(extremes(&s), extremes(&full));
+}
+
+#[test]
+fn rdp_straight_line() {
+    let data = (0..10_000).map(|x| [x as f64, 2.0 * x as f64]).collect();
+    assert_eq!(polyline_lens(&render(Decimation::Rdp(0.5), data)), [2]);
+}
+
+#[test]
+fn runs_are_decimated_separately() {
+    let mut data = noise(10_000);
+    data[5000][1] = f64::NAN;
+    assert_eq!(
+        polyline_lens(&render(Decimation::Lttb(100), data)),
+        [100, 100]
+    );
+}
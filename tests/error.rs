@@ -0,0 +1,112 @@
+use poloto::prelude::*;
+
+const DATA: [[f64; 2]; 3] = [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+
+struct FailingWriter;
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn io_error_is_kept() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("", DATA.iter().copied().twice_iter());
+    match plotter.render_io(FailingWriter) {
+        Err(poloto::Error::Io(e)) => assert_eq!(e.to_string(), "disk full"),
+        _ => panic!("expected an io error"),
+    }
+}
+
+#[test]
+fn display_error_is_fmt_error() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line(
+        poloto::moveable_format(|_| Err(core::fmt::Error)),
+        DATA.iter().copied().twice_iter(),
+    );
+    assert!(matches!(
+        plotter.render_to_string(),
+        Err(poloto::Error::Fmt(_))
+    ));
+}
+
+#[test]
+fn non_finite_baseline_is_invalid() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.baseline(poloto::Baseline::Constant(f64::NAN), false);
+    plotter.histogram("", DATA.iter().copied().twice_iter());
+    assert!(matches!(
+        plotter.render_to_string(),
+        Err(poloto::Error::InvalidData(_))
+    ));
+}
+
+#[derive(Debug)]
+struct CsvError;
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "bad csv row")
+    }
+}
+impl std::error::Error for CsvError {}
+
+#[test]
+fn try_iter_error_surfaces() {
+    let rows = vec![Ok([1.0, 2.0]), Err(CsvError), Ok([3.0, 4.0])];
+
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("", rows.into_iter().try_buffer_iter());
+    match plotter.render_to_string() {
+        Err(poloto::Error::Iter(e)) => assert_eq!(e.to_string(), "bad csv row"),
+        _ => panic!("expected an iterator error"),
+    }
+}
+
+#[test]
+fn try_iter_io_error_is_io() {
+    let rows = [Ok([1.0, 2.0]), Err(std::io::ErrorKind::UnexpectedEof)];
+
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.scatter(
+        "",
+        rows.iter()
+            .map(|a| a.map_err(std::io::Error::from))
+            .try_twice_iter(),
+    );
+    assert!(matches!(
+        plotter.render_to_string(),
+        Err(poloto::Error::Io(_))
+    ));
+}
+
+#[test]
+fn try_iter_without_errors() {
+    let rows: Vec<Result<[f64; 2], CsvError>> = vec![Ok([1.0, 2.0]), Ok([3.0, 4.0])];
+
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("", rows.into_iter().try_buffer_iter());
+    assert!(plotter.render_to_string().is_ok());
+}
+
+#[test]
+fn no_plots_is_invalid() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    assert!(matches!(
+        plotter.render_to_string(),
+        Err(poloto::Error::InvalidData("no plots"))
+    ));
+}
+
+#[test]
+fn figure_without_panels_is_invalid() {
+    let mut figure = poloto::Figure::new("figure", 2);
+    assert!(matches!(
+        figure.render_to_string(),
+        Err(poloto::Error::InvalidData("no plots"))
+    ));
+}
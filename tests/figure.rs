@@ -0,0 +1,87 @@
+use poloto::prelude::*;
+
+fn figure(share: bool) -> String {
+    let mut figure = poloto::Figure::new("figure", 2);
+    if share {
+        figure.share_x().share_y();
+    }
+    for i in 0..3 {
+        let scale = (i + 1) as f64;
+        let mut plotter = poloto::plot("panel", "x", "y");
+        plotter.line(
+            "data",
+            (0..10)
+                .map(move |x| [x as f64 * scale, x as f64 * scale * 10.0])
+                .twice_iter(),
+        );
+        figure.add(plotter);
+    }
+    figure.render_to_string().unwrap()
+}
+
+//The svg of every panel.
+fn panels(s: &str) -> Vec<&str> {
+    s.split("<svg x=").skip(1).collect()
+}
+
+fn ylabels(panel: &str) -> usize {
+    panel.matches("text-anchor=\"end\"").count()
+}
+
+fn xlabels(panel: &str) -> usize {
+    panel
+        .matches("alignment-baseline=\"start\" text-anchor=\"middle\" x=")
+        .count()
+}
+
+#[test]
+fn panels_are_laid_out_in_a_grid() {
+    let s = figure(false);
+    assert!(s.starts_with("<svg class=\"poloto\" width=\"1600\" height=\"1080\""));
+    assert_eq!(s.matches("<style>").count(), 1);
+
+    let panels = panels(&s);
+    assert_eq!(panels.len(), 3);
+    assert!(panels[0].starts_with("\"0\" y=\"80\""));
+    assert!(panels[1].starts_with("\"800\" y=\"80\""));
+    assert!(panels[2].starts_with("\"0\" y=\"580\""));
+
+    //Without sharing, each panel has its own tick labels.
+    for p in panels {
+        assert!(ylabels(p) > 0);
+        assert!(xlabels(p) > 2);
+    }
+}
+
+#[test]
+fn shared_axes_only_label_outer_edges() {
+    let s = figure(true);
+    let panels = panels(&s);
+
+    assert!(ylabels(panels[0]) > 0);
+    assert_eq!(ylabels(panels[1]), 0);
+    assert!(ylabels(panels[2]) > 0);
+
+    //The second panel has no panel below it, so it keeps its x labels.
+    assert_eq!(xlabels(panels[0]), 0);
+    assert!(xlabels(panels[1]) > 2);
+    assert!(xlabels(panels[2]) > 2);
+
+    //With shared bounds, every panel draws the same ticks.
+    let ticks = |p: &str| p.matches("<line").count();
+    assert_eq!(ticks(panels[0]), ticks(panels[2]));
+}
+
+#[test]
+fn used_up_panel_is_an_error() {
+    let mut plotter = poloto::plot("panel", "x", "y");
+    plotter.line("data", [[1.0, 2.0], [3.0, 4.0]].iter().buffer_iter());
+    let mut figure = poloto::Figure::new("figure", 1);
+    figure.add(plotter);
+
+    assert!(figure.render_to_string().is_ok());
+    assert!(matches!(
+        figure.render_to_string(),
+        Err(poloto::Error::InvalidData(_))
+    ));
+}
@@ -0,0 +1,69 @@
+use poloto::iter::*;
+
+fn temp_files() -> usize {
+    let prefix = format!("poloto-{}-", std::process::id());
+    std::fs::read_dir(std::env::temp_dir())
+        .unwrap()
+        .filter(|e| {
+            e.as_ref()
+                .map(|e| e.file_name().to_string_lossy().starts_with(&prefix))
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+#[test]
+#[cfg(unix)]
+fn temp_file_is_unlinked_when_created() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line(
+        "",
+        file_buffer((0..10).map(|x| [x as f64, 0.1 * x as f64])).unwrap(),
+    );
+    assert_eq!(temp_files(), 0);
+
+    //The data is still there through the open file.
+    let s = plotter.render_to_string().unwrap();
+    assert!(s.contains("<polyline"));
+    assert_eq!(temp_files(), 0);
+}
+
+#[test]
+#[cfg(not(unix))]
+fn temp_file_removed_after_render() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line(
+        "",
+        file_buffer((0..10).map(|x| [x as f64, 0.1 * x as f64])).unwrap(),
+    );
+    assert_eq!(temp_files(), 1);
+    plotter.render_to_string().unwrap();
+    assert_eq!(temp_files(), 0);
+
+    //Also removed if the plot is never rendered.
+    drop(file_buffer(vec![[1.0, 2.0]]).unwrap());
+    assert_eq!(temp_files(), 0);
+}
+
+#[test]
+fn points_read_back_exactly() {
+    let points: Vec<[f64; 2]> = (0..100)
+        .map(|x| [x as f64 / 7.0, 0.1 + 0.2 * x as f64])
+        .collect();
+
+    let path = std::env::temp_dir().join(format!("poloto-test-{}.bin", std::process::id()));
+
+    let mut buffer = file_buffer_at(points.clone(), &path).unwrap();
+    let mut first = Vec::new();
+    while let Some(a) = buffer.try_next() {
+        first.push(a.unwrap());
+    }
+    let second: Vec<_> = buffer.try_finish_first().map(|a| a.unwrap()).collect();
+
+    assert_eq!(first, points);
+    assert_eq!(second, points);
+
+    //The path based buffer is left behind for inspection.
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), 16 * 100);
+    std::fs::remove_file(&path).unwrap();
+}
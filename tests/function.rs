@@ -0,0 +1,50 @@
+mod common;
+use common::polylines;
+
+#[test]
+fn denser_where_curved() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.function("", 0.02..=1.0, |x| (1.0 / x).sin());
+    let s = plotter.render_to_string().unwrap();
+
+    let lines = polylines(&s);
+    assert_eq!(lines.len(), 1);
+
+    let line = &lines[0];
+    let [first, last] = [line[0][0], line[line.len() - 1][0]];
+    let tenth = (last - first) / 10.0;
+    let left = line.iter().filter(|a| a[0] < first + tenth).count();
+    let right = line.iter().filter(|a| a[0] > last - tenth).count();
+    assert!(left > right * 4, "{} {}", left, right);
+}
+
+#[test]
+fn straight_line_is_not_refined() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.function("", 0.0..=1.0, |x| 2.0 * x + 1.0);
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(polylines(&s)[0].len(), 65);
+}
+
+#[test]
+fn gap_at_pole() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.function(
+        "",
+        -1.0..=1.0,
+        |x| if x == 0.0 { f64::NAN } else { x.signum() },
+    );
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(polylines(&s).len(), 2);
+}
+
+#[test]
+fn parametric_closed_curve() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.parametric("", 0.0..=std::f64::consts::TAU, |t| [t.cos(), t.sin()]);
+    let s = plotter.render_to_string().unwrap();
+
+    let line = &polylines(&s)[0];
+    let [a, b] = [line[0], line[line.len() - 1]];
+    assert!((a[0] - b[0]).abs() < 1e-6 && (a[1] - b[1]).abs() < 1e-6);
+}
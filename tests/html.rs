@@ -0,0 +1,57 @@
+use poloto::prelude::*;
+
+fn plotter<'a>() -> poloto::Plotter<'a, impl poloto::build::Names> {
+    let mut plotter = poloto::plot("my title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter.y_axis(poloto::YAxis::Secondary);
+    plotter.scatter("b", [[0.0, 0.0], [10.0, 100.0]].iter().twice_iter());
+    plotter
+}
+
+#[test]
+fn page_is_self_contained() {
+    let s = plotter().render_html(String::new()).unwrap();
+    assert!(s.starts_with("<!DOCTYPE html>"));
+    assert!(s.contains("<title>my title</title>"));
+    assert!(s.contains("<svg class=\"poloto\""));
+    assert!(s.contains("<script>"));
+    assert!(s.trim_end().ends_with("</html>"));
+    assert!(!s.contains("src="));
+    assert!(!s.contains("href="));
+}
+
+#[test]
+fn plot_area_has_the_axis_transforms() {
+    let s = plotter().render_html(String::new()).unwrap();
+    assert!(s.contains("<rect class=\"poloto_plot_area\" fill=\"none\" pointer-events=\"all\" x=\"150\" y=\"100\" width=\"425\" height=\"300\" data-x=\"0 10 150 575\" data-y=\"0 10 400 100\" data-y2=\"0 100 400 100\"/>"));
+}
+
+#[test]
+fn plots_and_legends_are_marked() {
+    let s = plotter().render_html(String::new()).unwrap();
+    //The legend text and marker of each plot.
+    assert_eq!(s.matches("data-legend=\"0\"").count(), 2);
+    assert_eq!(s.matches("data-legend=\"1\"").count(), 2);
+    assert_eq!(s.matches("data-series=\"0\"").count(), 1);
+    assert_eq!(s.matches("data-series=\"1\"").count(), 1);
+}
+
+#[test]
+fn svg_output_is_unchanged() {
+    let mut plotter = plotter();
+    let before = plotter.render_to_string().unwrap();
+    plotter.render_html(String::new()).unwrap();
+    let after = plotter.render_to_string().unwrap();
+
+    assert_eq!(before, after);
+    assert!(!after.contains("data-"));
+    assert!(!after.contains("poloto_plot_area"));
+}
+
+#[test]
+fn title_is_escaped() {
+    let mut plotter = poloto::plot("a<b&c", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    let s = plotter.render_html(String::new()).unwrap();
+    assert!(s.contains("<title>a&lt;b&amp;c</title>"));
+}
@@ -0,0 +1,88 @@
+mod common;
+use common::{attr, polylines, tags};
+use poloto::prelude::*;
+
+const DATA: [[f64; 2]; 3] = [[0.0, 1.0], [5.0, 2.0], [10.0, 3.0]];
+
+//The tick labels of an axis, as the svg coordinate along the axis and the text.
+fn ticks(s: &str, axis: &str) -> Vec<(f64, String)> {
+    let (pos, other) = match axis {
+        "x" => ("x", " y=\"430\">"),
+        _ => ("y", " x=\"135\" "),
+    };
+    s.split("<text ")
+        .skip(1)
+        .filter(|t| t.contains(other))
+        .map(|t| {
+            let text = &t[t.find('>').unwrap() + 1..t.find("</text>").unwrap()];
+            (attr(t, pos), text.to_string())
+        })
+        .collect()
+}
+
+//The bars of the first histogram.
+fn bars(s: &str) -> Vec<&str> {
+    let g = &s[s.find("<g ").unwrap()..];
+    tags(&g[..g.find("</g>").unwrap()], "rect")
+}
+
+#[test]
+fn lines_are_flipped() {
+    let mut plotter = common::plotter();
+    plotter.invert_x();
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(polylines(&s), vec![vec![[650.0, 400.0], [150.0, 100.0]]]);
+
+    let mut plotter = common::plotter();
+    plotter.invert_y();
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(polylines(&s), vec![vec![[150.0, 100.0], [650.0, 400.0]]]);
+}
+
+#[test]
+fn ticks_are_flipped() {
+    let mut plotter = common::plotter();
+    plotter.invert_x().invert_y();
+    let s = plotter.render_to_string().unwrap();
+
+    let x = ticks(&s, "x");
+    assert_eq!(x.first().unwrap(), &(650.0, "0".to_string()));
+    assert_eq!(x.last().unwrap(), &(150.0, "10".to_string()));
+    assert!(x.windows(2).all(|w| w[0].0 > w[1].0));
+
+    let y = ticks(&s, "y");
+    assert_eq!(y.first().unwrap(), &(100.0, "0".to_string()));
+    assert_eq!(y.last().unwrap(), &(400.0, "10".to_string()));
+    assert!(y.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn histogram_bars_extend_left_with_inverted_x() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.histogram("data", DATA.iter().twice_iter());
+    plotter.invert_x();
+    let s = plotter.render_to_string().unwrap();
+
+    let bars: Vec<_> = bars(&s)
+        .into_iter()
+        .map(|t| [attr(t, "x"), attr(t, "width")])
+        .collect();
+    assert_eq!(bars, vec![[403.0, 247.0], [153.0, 247.0]]);
+}
+
+#[test]
+fn bottom_baseline_is_at_the_top_with_inverted_y() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line_fill("fill", DATA.iter().twice_iter());
+    plotter.histogram("histo", DATA.iter().twice_iter());
+    plotter.invert_y();
+    let s = plotter.render_to_string().unwrap();
+
+    assert!(s.contains(" d=\" M 150 100 L 150 100 L 400 250 L 650 400 L 650 100Z\""));
+
+    let bars: Vec<_> = bars(&s)
+        .into_iter()
+        .map(|t| [attr(t, "y"), attr(t, "height")])
+        .collect();
+    assert_eq!(bars, vec![[100.0, 0.0], [100.0, 150.0]]);
+}
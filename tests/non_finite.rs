@@ -0,0 +1,120 @@
+use poloto::prelude::*;
+
+//A series with a gap in the middle, plus some points that are not finite at all.
+const DATA: [[f64; 2]; 6] = [
+    [1.0, 4.0],
+    [2.0, 5.0],
+    [3.0, f64::NAN],
+    [f64::INFINITY, 2.0],
+    [5.0, 6.0],
+    [6.0, 3.0],
+];
+
+type Plotter = poloto::Plotter<
+    'static,
+    poloto::build::NamesStruct<
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    >,
+>;
+
+fn render(func: impl FnOnce(&mut Plotter)) -> String {
+    let mut plotter = poloto::build::PlotterBuilder::new().build("title", "x", "y");
+    func(&mut plotter);
+    plotter.render_to_string().unwrap()
+}
+
+fn assert_no_bad_numbers(s: &str) {
+    assert!(!s.contains("NaN"), "{}", s);
+    assert!(!s.contains("inf"), "{}", s);
+}
+
+#[test]
+fn bounds_ignore_non_finite() {
+    let s = render(|p| {
+        p.scatter("", DATA.iter().copied().twice_iter());
+    });
+    assert_no_bad_numbers(&s);
+
+    //The x axis should only go up to 6, not infinity.
+    assert!(s.contains(">6</text>"), "{}", s);
+}
+
+#[test]
+fn only_non_finite() {
+    let s = render(|p| {
+        p.line(
+            "",
+            [[f64::NAN, 1.0], [2.0, f64::INFINITY]]
+                .iter()
+                .copied()
+                .twice_iter(),
+        );
+    });
+    assert_no_bad_numbers(&s);
+    assert!(!s.contains("<polyline"), "{}", s);
+}
+
+#[test]
+fn scatter_skips_non_finite() {
+    let s = render(|p| {
+        p.scatter("", DATA.iter().copied().twice_iter());
+    });
+    assert_eq!(s.matches("<circle").count(), 4);
+}
+
+#[test]
+fn histogram_skips_non_finite() {
+    let s = render(|p| {
+        p.histogram("", DATA.iter().copied().twice_iter());
+    });
+    assert_no_bad_numbers(&s);
+
+    //Four finite points make three bars, plus the background and the clip area.
+    assert_eq!(s.matches("<rect").count(), 2 + 3);
+}
+
+#[test]
+fn line_splits_at_non_finite() {
+    let s = render(|p| {
+        p.line("", DATA.iter().copied().twice_iter());
+    });
+    assert_no_bad_numbers(&s);
+    assert_eq!(s.matches("<polyline").count(), 2);
+}
+
+#[test]
+fn step_splits_at_non_finite() {
+    let s = render(|p| {
+        p.step_after("", DATA.iter().copied().twice_iter());
+    });
+    assert_no_bad_numbers(&s);
+    assert_eq!(s.matches("<polyline").count(), 2);
+}
+
+#[test]
+fn line_fill_splits_at_non_finite() {
+    let s = render(|p| {
+        p.line_fill("", DATA.iter().copied().twice_iter());
+    });
+    assert_no_bad_numbers(&s);
+
+    //One subpath per run of finite points.
+    assert_eq!(s.matches('Z').count(), 2);
+}
+
+#[test]
+fn line_fill_between_splits_at_non_finite() {
+    let s = render(|p| {
+        p.line_fill_between(
+            "",
+            DATA.iter().copied().twice_iter(),
+            DATA.iter().map(|&[x, y]| [x, y - 1.0]).twice_iter(),
+        );
+    });
+    assert_no_bad_numbers(&s);
+    assert_eq!(s.matches('Z').count(), 2);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "png")]
+
+use poloto::prelude::*;
+
+fn plotter<'a>() -> poloto::Plotter<'a, impl poloto::build::Names> {
+    let mut plotter = poloto::plot("my title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter
+}
+
+//The width and height from the header of a png.
+fn size(png: &[u8]) -> [u32; 2] {
+    let int = |i: usize| u32::from_be_bytes([png[i], png[i + 1], png[i + 2], png[i + 3]]);
+    [int(16), int(20)]
+}
+
+#[test]
+fn size_follows_dpi() {
+    let png = plotter().render_png(Vec::new(), 96.0).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert_eq!(size(&png), [800, 500]);
+
+    let png = plotter().render_png(Vec::new(), 48.0).unwrap();
+    assert_eq!(size(&png), [400, 250]);
+}
+
+#[test]
+fn svg_tag_option_is_ignored() {
+    let mut plotter = poloto::build::PlotterBuilder::new()
+        .with_svg(false)
+        .build("my title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    let png = plotter.render_png(Vec::new(), 96.0).unwrap();
+    assert_eq!(size(&png), [800, 500]);
+}
+
+#[test]
+fn too_big() {
+    for dpi in [1.0e6, 1.0e12] {
+        let res = plotter().render_png(Vec::new(), dpi);
+        assert!(matches!(res, Err(poloto::Error::Raster(_))));
+    }
+}
+
+#[test]
+fn bad_dpi() {
+    for dpi in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        let res = plotter().render_png(Vec::new(), dpi);
+        assert!(matches!(res, Err(poloto::Error::InvalidData(_))));
+    }
+}
+
+//A plotter whose text is drawn with the given font family.
+fn with_font<'a>(family: &'a str) -> poloto::Plotter<'a, impl poloto::build::Names + 'a> {
+    let mut plotter = poloto::build::PlotterBuilder::new()
+        .with_header(poloto::move_format!(
+            "<style>.poloto{{font-family:\"{}\";}}</style>",
+            family
+        ))
+        .build("my title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter
+}
+
+#[test]
+fn missing_font_falls_back_to_the_fallback_list() {
+    let mut db = resvg::usvg::fontdb::Database::new();
+    db.load_system_fonts();
+    let installed = [
+        "Arial",
+        "Helvetica",
+        "Liberation Sans",
+        "DejaVu Sans",
+        "Noto Sans",
+        "FreeSans",
+    ]
+    .iter()
+    .copied()
+    .find(|name| {
+        db.faces()
+            .any(|face| face.families.iter().any(|(family, _)| family == *name))
+    });
+    //Without any of them, any installed font is used, which can't be known here.
+    let installed = match installed {
+        Some(installed) => installed,
+        None => return,
+    };
+
+    let missing = with_font("no such font")
+        .render_png(Vec::new(), 96.0)
+        .unwrap();
+    let fallback = with_font(installed).render_png(Vec::new(), 96.0).unwrap();
+    assert!(missing == fallback);
+}
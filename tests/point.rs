@@ -0,0 +1,77 @@
+mod common;
+use poloto::prelude::*;
+
+fn render_line<D: poloto::iter::TryDoubleIterator + 'static>(plots: D) -> String {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("data", plots);
+    common::without_ids(&plotter.render_to_string().unwrap())
+}
+
+#[test]
+fn numeric_points_match_f64() {
+    let expected = render_line(
+        [[1.0f64, 4.0], [2.0, 5.0], [3.0, 6.0]]
+            .iter()
+            .copied()
+            .twice_iter(),
+    );
+
+    assert_eq!(
+        render_line([[1i32, 4], [2, 5], [3, 6]].iter().copied().twice_iter()),
+        expected
+    );
+    assert_eq!(
+        render_line([[1u8, 4], [2, 5], [3, 6]].iter().copied().twice_iter()),
+        expected
+    );
+    assert_eq!(
+        render_line(
+            [(1.0f32, 4.0), (2.0, 5.0), (3.0, 6.0)]
+                .iter()
+                .copied()
+                .twice_iter()
+        ),
+        expected
+    );
+    assert_eq!(
+        render_line((1..4u64).map(|x| (x, x + 3)).buffer_iter()),
+        expected
+    );
+}
+
+#[test]
+fn user_points() {
+    #[derive(Copy, Clone)]
+    struct Sample {
+        time: usize,
+        value: f32,
+    }
+    impl poloto::PlotPoint for Sample {
+        fn to_point(&self) -> [f64; 2] {
+            [self.time as f64, self.value as f64]
+        }
+    }
+
+    static DATA: [Sample; 3] = [
+        Sample {
+            time: 1,
+            value: 4.0,
+        },
+        Sample {
+            time: 2,
+            value: 5.0,
+        },
+        Sample {
+            time: 3,
+            value: 6.0,
+        },
+    ];
+
+    let expected = render_line(
+        [[1.0f64, 4.0], [2.0, 5.0], [3.0, 6.0]]
+            .iter()
+            .copied()
+            .twice_iter(),
+    );
+    assert_eq!(render_line(DATA.iter().twice_iter()), expected);
+}
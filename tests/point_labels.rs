@@ -0,0 +1,64 @@
+use poloto::prelude::*;
+
+fn labels(s: &str, class: &str) -> Vec<String> {
+    let start = match s.find(&format!("poloto_text {}\"", class)) {
+        Some(start) => start,
+        None => return Vec::new(),
+    };
+    let g = &s[start..];
+    let g = &g[..g.find("</g>").unwrap()];
+    g.split("<text ").skip(1).map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn scatter_labels_are_above_the_points() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter
+        .scatter("data", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter())
+        .point_labels(|[x, y]| format!("{},{}", x, y));
+    let s = plotter.render_to_string().unwrap();
+
+    let l = labels(&s, "poloto0label");
+    assert_eq!(l.len(), 2);
+    assert!(l[0].contains("x=\"150\" y=\"390\">0,0</text>"));
+    assert!(l[1].contains("x=\"650\" y=\"90\">10,10</text>"));
+}
+
+#[test]
+fn bar_labels_are_past_the_end_of_the_bars() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.baseline(poloto::Baseline::Zero, true);
+    plotter
+        .histogram("data", [[0, 10], [1, -10], [2, 0]].iter().twice_iter())
+        .point_labels_from(["up", "down"].iter());
+    let s = plotter.render_to_string().unwrap();
+
+    //There is no bar for the last point.
+    let l = labels(&s, "poloto0label");
+    assert_eq!(l.len(), 2);
+    assert!(l[0].contains("alignment-baseline=\"baseline\""));
+    assert!(l[0].contains("y=\"90\">up</text>"));
+    assert!(l[1].contains("alignment-baseline=\"hanging\""));
+    assert!(l[1].contains("y=\"410\">down</text>"));
+}
+
+#[test]
+fn labels_by_index_skip_non_finite_points() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("first", [[0.0, 0.0], [1.0, 1.0]].iter().twice_iter());
+    plotter
+        .scatter(
+            "data",
+            [[0.0, 0.0], [f64::NAN, 0.5], [1.0, 1.0]]
+                .iter()
+                .twice_iter(),
+        )
+        .point_labels_from(["a", "b", "c"].iter());
+    let s = plotter.render_to_string().unwrap();
+
+    //The class is for the second plot.
+    let l = labels(&s, "poloto1label");
+    assert_eq!(l.len(), 2);
+    assert!(l[0].ends_with(">a</text>"));
+    assert!(l[1].ends_with(">c</text>"));
+}
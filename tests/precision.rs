@@ -0,0 +1,42 @@
+use poloto::prelude::*;
+
+fn render(precision: Option<usize>) -> String {
+    let mut plotter = poloto::plot("title", "x", "y");
+    if let Some(p) = precision {
+        plotter.precision(p);
+    }
+    let x = (0..500).map(|x| x as f64 / 7.0);
+    plotter.line("line", x.clone().map(|x| [x, x.sin()]).twice_iter());
+    plotter.scatter("scatter", x.clone().map(|x| [x, x.cos()]).twice_iter());
+    plotter.histogram(
+        "histo",
+        x.clone().step_by(20).map(|x| [x, x.cos()]).twice_iter(),
+    );
+    plotter.line_fill("fill", x.map(|x| [x, x.cos() - 1.0]).twice_iter());
+    plotter.render_to_string().unwrap()
+}
+
+//The most decimal places of any number written inside a tag.
+fn max_decimals(s: &str) -> usize {
+    s.split('<')
+        .filter_map(|a| a.split('>').next())
+        .flat_map(|tag| tag.split(|c: char| !(c.is_ascii_digit() || c == '.')))
+        .filter_map(|num| num.split('.').nth(1))
+        .map(|d| d.len())
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn coordinates_are_rounded() {
+    let full = render(None);
+    assert!(max_decimals(&full) > 5);
+
+    let rounded = render(Some(2));
+    assert!(max_decimals(&rounded) <= 2, "{}", rounded);
+    assert!(rounded.len() * 3 < full.len() * 2);
+
+    let whole = render(Some(0));
+    assert_eq!(max_decimals(&whole), 0);
+    assert!(!whole.contains("-0 ") && !whole.contains("-0,"));
+}
@@ -0,0 +1,74 @@
+mod common;
+use common::{annotations, plotter, polylines, without_ids};
+use poloto::prelude::*;
+
+#[test]
+fn lines_cross_the_plot_area() {
+    let mut plotter = plotter();
+    plotter.hline(5.0, "h", false);
+    plotter.vline(5.0, "v", false);
+    let s = plotter.render_to_string().unwrap();
+
+    let a = annotations(&s);
+    assert_eq!(a.len(), 2);
+
+    //The plot area goes from 150 to 650 horizontally, and from 400 to 100 vertically.
+    assert!(a[0].contains("x1=\"150\" y1=\"250\" x2=\"650\" y2=\"250\""));
+    assert!(a[0].contains(">h"));
+    assert!(a[1].contains("x1=\"400\" y1=\"100\" x2=\"400\" y2=\"400\""));
+    assert!(a[1].contains(">v"));
+}
+
+#[test]
+fn included_lines_extend_the_bounds() {
+    let mut plotter = plotter();
+    plotter.hline(20.0, "", true);
+    plotter.vline(-10.0, "", false);
+    let s = plotter.render_to_string().unwrap();
+
+    let a = annotations(&s);
+    //The y axis now goes up to the horizontal line.
+    assert!(a[0].contains("y1=\"100\" x2=\"650\" y2=\"100\""));
+    //The vertical line was not included, so it is outside of the plot area.
+    assert!(a[1].contains("x1=\"-350\""));
+    assert!(a[1].starts_with("clip-path="));
+}
+
+#[test]
+fn spans_are_drawn_behind_the_plots() {
+    let mut plotter = plotter();
+    plotter.line("after", [[0.0, 1.0], [10.0, 11.0]].iter().twice_iter());
+    plotter.xspan(6.0..=2.0, "incident", false);
+    plotter.yspan(0.0..=20.0, "", true);
+    let s = plotter.render_to_string().unwrap();
+
+    let a = annotations(&s);
+    assert_eq!(a.len(), 2);
+    //A reversed range is the same as the sorted range.
+    assert!(a[0].contains("<rect stroke=\"none\" fill-opacity=\"0.2\" x=\"250\" y=\"100\" width=\"200\" height=\"300\"/>"));
+    assert!(a[1].contains("x=\"150\" y=\"100\" width=\"500\" height=\"300\"/>"));
+
+    let span = s.find("fill-opacity").unwrap();
+    let line = s.find("<polyline").unwrap();
+    assert!(span < line);
+}
+
+#[test]
+fn lines_on_an_axis_without_plots_dont_change_bounds() {
+    let mut plotter = plotter();
+    plotter.y_axis(poloto::YAxis::Secondary);
+    plotter.hline(100.0, "", true);
+    let a = plotter.render_to_string().unwrap();
+    let b = self::plotter().render_to_string().unwrap();
+    assert_eq!(polylines(&a), polylines(&b));
+}
+
+#[test]
+fn non_finite_lines_are_left_out() {
+    let mut plotter = plotter();
+    plotter.hline(f64::NAN, "", true);
+    plotter.xspan(0.0..=f64::INFINITY, "", true);
+    let a = plotter.render_to_string().unwrap();
+    let b = self::plotter().render_to_string().unwrap();
+    assert_eq!(without_ids(&a), without_ids(&b));
+}
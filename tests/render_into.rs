@@ -0,0 +1,78 @@
+use poloto::prelude::*;
+use poloto::tagger::prelude::*;
+
+fn plotter<'a>() -> poloto::Plotter<'a, impl poloto::build::Names> {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line(
+        "data",
+        [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]].iter().twice_iter(),
+    );
+    plotter
+}
+
+fn render_into(
+    plotter: &mut poloto::Plotter<impl poloto::build::Names>,
+    background: bool,
+) -> String {
+    let mut root = poloto::tagger::Element::new(String::new());
+    root.elem("svg", |w| {
+        let svg = w.write(|w| w.attr("width", 1000))?;
+        plotter
+            .render_into(svg, [100.0, 50.0, 400.0, 250.0], background)
+            .unwrap();
+        Ok(svg)
+    })
+    .unwrap();
+    root.into_writer()
+}
+
+#[test]
+fn placed_in_a_nested_viewport() {
+    let s = render_into(&mut plotter(), true);
+    assert!(s.starts_with(
+        "<svg width=\"1000\"><svg x=\"100\" y=\"50\" width=\"400\" height=\"250\" viewBox=\"0 0 800 500\">"
+    ));
+    assert!(s.ends_with("</svg></svg>"));
+    assert_eq!(s.matches("class=\"poloto_background\"").count(), 1);
+}
+
+#[test]
+fn background_can_be_left_out() {
+    let mut plotter = plotter();
+    let with = render_into(&mut plotter, true);
+    let without = render_into(&mut plotter, false);
+    assert!(!without.contains("class=\"poloto_background\""));
+    assert_eq!(with.matches("<polyline").count(), 1);
+    assert_eq!(without.matches("<polyline").count(), 1);
+}
+
+#[test]
+fn used_up_data_is_an_error() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("data", [[1.0, 4.0], [2.0, 5.0]].iter().buffer_iter());
+    plotter.render_to_string().unwrap();
+
+    let mut root = poloto::tagger::Element::new(String::new());
+    let res = plotter.render_into(&mut root, [0.0, 0.0, 400.0, 250.0], true);
+    assert!(matches!(res, Err(poloto::Error::InvalidData(_))));
+    assert_eq!(root.into_writer(), "");
+}
+
+#[test]
+fn header_is_left_out() {
+    let mut plotter = plotter();
+    let mut root = poloto::tagger::Element::new(String::new());
+    root.elem("svg", |w| {
+        let svg = w.write(|w| w.attr("width", 1000))?;
+        for x in [0.0, 500.0] {
+            plotter
+                .render_into(svg, [x, 0.0, 400.0, 250.0], true)
+                .unwrap();
+        }
+        Ok(svg)
+    })
+    .unwrap();
+    let s = root.into_writer();
+    assert_eq!(s.matches("<svg x=").count(), 2);
+    assert_eq!(s.matches("<style").count(), 0);
+}
@@ -0,0 +1,78 @@
+use poloto::prelude::*;
+
+fn plotter<'a>(title: &'a str) -> poloto::Plotter<'a, impl poloto::build::Names + 'a> {
+    let mut plotter = poloto::plot(title, "x", "y");
+    plotter.line("data", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter
+}
+
+#[test]
+fn sections_are_written_in_order() {
+    let mut report = poloto::Report::new("report");
+    report
+        .heading("first")
+        .plot(plotter("a"))
+        .paragraph("between")
+        .plot(plotter("b"));
+    let s = report.render_to_string().unwrap();
+
+    assert!(s.starts_with("<!DOCTYPE html>"));
+    assert!(s.contains("<title>report</title>"));
+    let order = ["<h2>first</h2>", ">a</text>", "<p>between</p>", ">b</text>"]
+        .iter()
+        .map(|a| s.find(a).unwrap())
+        .collect::<Vec<_>>();
+    assert!(order.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(s.matches("<svg class=\"poloto\"").count(), 2);
+}
+
+#[test]
+fn plots_share_one_stylesheet() {
+    let mut report = poloto::Report::new("report");
+    report.plot(plotter("a")).plot(plotter("b"));
+    let s = report.render_to_string().unwrap();
+
+    //The default css of each plotter is left out.
+    assert_eq!(s.matches(".poloto0stroke").count(), 1);
+    assert!(s.contains("var(--poloto_color0,blue)"));
+    //The dark theme sets the variables.
+    assert!(s.contains("body.poloto_dark .poloto{"));
+    assert!(s.contains("class=\"poloto_theme\""));
+}
+
+#[test]
+fn custom_style() {
+    let mut report = poloto::Report::new("report");
+    report.style(
+        poloto::build::StyleBuilder::new()
+            .with_colors(["a", "b", "c", "d", "e", "f", "g", "h"])
+            .build_with_css_variables(),
+    );
+    let s = report.render_to_string().unwrap();
+    assert!(s.contains("var(--poloto_color0,a)"));
+    assert!(!s.contains("var(--poloto_color0,blue)"));
+}
+
+#[test]
+fn used_up_plot_is_an_error() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("data", [[0.0, 0.0], [1.0, 1.0]].iter().buffer_iter());
+    let mut report = poloto::Report::new("report");
+    report.plot(plotter);
+
+    assert!(report.render_to_string().is_ok());
+    assert!(matches!(
+        report.render(String::new()),
+        Err(poloto::Error::InvalidData(_))
+    ));
+}
+
+#[test]
+fn title_is_escaped() {
+    let mut report = poloto::Report::new("a<b&c");
+    report.plot(plotter("a"));
+    let s = report.render_to_string().unwrap();
+    assert!(s.contains("<title>a&lt;b&amp;c</title>"));
+    assert!(s.contains("<h1>a&lt;b&amp;c</h1>"));
+    assert!(!s.contains("a<b"));
+}
@@ -0,0 +1,44 @@
+use poloto::prelude::*;
+
+const DATA: [[f64; 2]; 3] = [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+
+#[test]
+fn restartable_data_renders_twice() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("line", DATA.iter().copied().twice_iter());
+    plotter.line_fill_between(
+        "fill",
+        DATA.to_vec().twice_iter(),
+        DATA.iter().map(|&[x, y]| [x, y - 1.0]).twice_iter(),
+    );
+    plotter.scatter(
+        "try",
+        DATA.iter()
+            .map(|&a| Ok::<_, std::io::Error>(a))
+            .try_twice_iter(),
+    );
+    plotter.stacked_area(poloto::StackedArea::new().layer("stack", DATA.iter().copied()));
+
+    let first = plotter.render_to_string().unwrap();
+    let second = plotter.render_to_string().unwrap();
+    assert_eq!(first, second);
+
+    let third = plotter.render_io(Vec::new()).unwrap();
+    assert_eq!(first.as_bytes(), &third[..]);
+}
+
+#[test]
+fn buffered_data_is_used_up() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("twice", DATA.iter().copied().twice_iter());
+    plotter.line("buffer", DATA.iter().copied().buffer_iter());
+
+    assert!(plotter.render_to_string().is_ok());
+
+    let mut s = String::new();
+    assert!(matches!(
+        plotter.render(&mut s),
+        Err(poloto::Error::InvalidData(_))
+    ));
+    assert!(s.is_empty());
+}
@@ -0,0 +1,36 @@
+use poloto::build::*;
+use poloto::prelude::*;
+
+//The x attribute of the first element that has the given attribute.
+fn x_of(s: &str, attr: &str) -> f64 {
+    let start = s.find(attr).unwrap();
+    let tag = &s[s[..start].rfind('<').unwrap()..];
+    let tag = &tag[..tag.find('>').unwrap()];
+    let x = &tag[tag.find(" x=\"").unwrap() + 4..];
+    x[..x.find('"').unwrap()].parse().unwrap()
+}
+
+#[test]
+fn legend_is_right_of_the_secondary_label() {
+    let mut plotter = PlotterBuilder::new()
+        .with_secondary_yname("y2")
+        .build("title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter.y_axis(poloto::YAxis::Secondary);
+    plotter.line("b", [[0.0, 0.0], [10.0, 100.0]].iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+
+    let legend = x_of(&s, "font-size=\"large\"");
+    let label = x_of(&s, "rotate(90,");
+    //The rotated label extends right of its x by about its x-large font size.
+    assert!(legend > label + 24.0, "{} {}", legend, label);
+}
+
+#[test]
+fn legend_without_secondary_axis_is_unchanged() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+
+    assert_eq!(x_of(&s, "font-size=\"large\""), 675.0);
+}
@@ -0,0 +1,141 @@
+mod common;
+use common::tags;
+use poloto::StackedArea;
+
+//The points of the fill of every layer, in svg coordinates.
+//Each fill traces its upper curve forward, then its lower curve backward.
+fn fills(s: &str) -> Vec<Vec<[f64; 2]>> {
+    tags(s, "path")
+        .into_iter()
+        .filter(|t| t.contains("fill\" d=\""))
+        .map(|t| {
+            let d = &t[t.find(" d=\"").unwrap() + 4..];
+            let n: Vec<f64> = d[..d.find('"').unwrap()]
+                .split(|c: char| c.is_whitespace() || c == 'Z')
+                .filter_map(|v| v.parse().ok())
+                .collect();
+            n.chunks(2).map(|p| [p[0], p[1]]).collect()
+        })
+        .collect()
+}
+
+fn render(stack: StackedArea) -> Vec<Vec<[f64; 2]>> {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.stacked_area(stack);
+    fills(&plotter.render_to_string().unwrap())
+}
+
+#[test]
+fn layers_are_stacked() {
+    //The top of the stack is 4, so the y axis goes from 0 to 4.
+    let f = render(
+        StackedArea::new()
+            .layer("a", [[0.0, 1.0], [10.0, 1.0]])
+            .layer("b", [[0.0, 3.0], [10.0, 1.0]]),
+    );
+    assert_eq!(
+        f,
+        vec![
+            vec![
+                [150.0, 325.0],
+                [650.0, 325.0],
+                [650.0, 400.0],
+                [150.0, 400.0]
+            ],
+            vec![
+                [150.0, 100.0],
+                [650.0, 250.0],
+                [650.0, 325.0],
+                [150.0, 325.0]
+            ],
+        ]
+    );
+}
+
+#[test]
+fn normalized_layers_add_up_to_100() {
+    let f = render(
+        StackedArea::new()
+            .layer("a", [[0.0, 1.0], [10.0, 1.0]])
+            .layer("b", [[0.0, 3.0], [10.0, 1.0]])
+            .normalized(),
+    );
+    assert_eq!(
+        f,
+        vec![
+            vec![
+                [150.0, 325.0],
+                [650.0, 250.0],
+                [650.0, 400.0],
+                [150.0, 400.0]
+            ],
+            vec![
+                [150.0, 100.0],
+                [650.0, 100.0],
+                [650.0, 250.0],
+                [150.0, 325.0]
+            ],
+        ]
+    );
+}
+
+#[test]
+fn normalized_zero_total_stays_at_zero() {
+    let f = render(
+        StackedArea::new()
+            .layer("a", [[0.0, 0.0], [10.0, 1.0]])
+            .layer("b", [[0.0, 0.0], [10.0, 1.0]])
+            .normalized(),
+    );
+    assert_eq!(
+        f,
+        vec![
+            vec![
+                [150.0, 400.0],
+                [650.0, 250.0],
+                [650.0, 400.0],
+                [150.0, 400.0]
+            ],
+            vec![
+                [150.0, 400.0],
+                [650.0, 100.0],
+                [650.0, 250.0],
+                [150.0, 400.0]
+            ],
+        ]
+    );
+}
+
+#[test]
+fn stack_is_as_long_as_its_shortest_layer() {
+    //The x positions of the first layer are used.
+    let f = render(
+        StackedArea::new()
+            .layer("a", [[0.0, 1.0], [10.0, 1.0], [20.0, 1.0]])
+            .layer("b", [[5.0, 3.0], [15.0, 1.0]]),
+    );
+    assert_eq!(
+        f,
+        vec![
+            vec![
+                [150.0, 325.0],
+                [650.0, 325.0],
+                [650.0, 400.0],
+                [150.0, 400.0]
+            ],
+            vec![
+                [150.0, 100.0],
+                [650.0, 250.0],
+                [650.0, 325.0],
+                [150.0, 325.0]
+            ],
+        ]
+    );
+}
+
+#[test]
+fn empty_stack_adds_no_plots() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.stacked_area(StackedArea::new());
+    assert!(plotter.render_to_string().is_err());
+}
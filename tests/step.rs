@@ -0,0 +1,71 @@
+mod common;
+use common::polylines;
+use poloto::prelude::*;
+
+//Goes up to the top of the y axis, then halfway down.
+const DATA: [[f64; 2]; 3] = [[0.0, 0.0], [5.0, 10.0], [10.0, 5.0]];
+
+#[test]
+fn step_before_changes_at_the_last_point() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.step_before("data", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(
+        polylines(&s),
+        vec![vec![
+            [150.0, 400.0],
+            [150.0, 100.0],
+            [400.0, 100.0],
+            [400.0, 250.0],
+            [650.0, 250.0]
+        ]]
+    );
+}
+
+#[test]
+fn step_after_changes_at_the_next_point() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.step_after("data", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(
+        polylines(&s),
+        vec![vec![
+            [150.0, 400.0],
+            [400.0, 400.0],
+            [400.0, 100.0],
+            [650.0, 100.0],
+            [650.0, 250.0]
+        ]]
+    );
+}
+
+#[test]
+fn step_mid_changes_halfway() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.step_mid("data", DATA.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(
+        polylines(&s),
+        vec![vec![
+            [150.0, 400.0],
+            [275.0, 400.0],
+            [275.0, 100.0],
+            [400.0, 100.0],
+            [525.0, 100.0],
+            [525.0, 250.0],
+            [650.0, 250.0]
+        ]]
+    );
+}
+
+#[test]
+fn steps_are_not_drawn_across_gaps() {
+    let data = [[0.0, 0.0], [5.0, f64::NAN], [10.0, 10.0]];
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.step_after("data", data.iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(
+        polylines(&s),
+        vec![vec![[150.0, 400.0]], vec![[650.0, 100.0]]]
+    );
+}
@@ -0,0 +1,123 @@
+use poloto::prelude::*;
+
+fn plotter<'a>() -> poloto::Plotter<'a, impl poloto::build::Names> {
+    let mut plotter = poloto::plot("my title", "x", "y");
+    plotter.line("a", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    plotter.scatter("b", [[0.0, 10.0], [10.0, 0.0]].iter().twice_iter());
+    plotter
+}
+
+#[test]
+fn output_has_the_requested_size() {
+    let s = plotter()
+        .render_terminal(String::new(), [40, 20], false)
+        .unwrap();
+    let lines: Vec<_> = s.lines().collect();
+    assert_eq!(lines.len(), 20);
+    assert!(lines[0].contains("my title"));
+    //Every row of the plot area, and the x axis below it, fills the width.
+    for line in &lines[2..16] {
+        assert_eq!(line.chars().count(), 40);
+    }
+    assert_eq!(lines[17].trim(), "x");
+    assert_eq!(lines[18], "  ── a");
+    assert_eq!(lines[19], "  •• b");
+}
+
+#[test]
+fn ticks_are_labelled() {
+    let s = plotter()
+        .render_terminal(String::new(), [40, 20], false)
+        .unwrap();
+    let lines: Vec<_> = s.lines().collect();
+    //The y axis goes from 10 at the top to 0 at the bottom.
+    assert!(lines[2].starts_with("10 ┤"));
+    assert!(lines[14].starts_with(" 0 ┤"));
+    assert!(lines[15].starts_with("   └┬"));
+    assert!(lines[16].trim_start().starts_with('0'));
+    assert!(lines[16].trim_end().ends_with("10"));
+}
+
+#[test]
+fn line_goes_from_corner_to_corner() {
+    let s = plotter()
+        .render_terminal(String::new(), [40, 20], false)
+        .unwrap();
+    let lines: Vec<_> = s.lines().collect();
+    //The bottom left and top right braille cells have a dot in their corner.
+    let first = |line: &str| line.chars().nth(4).unwrap() as u32 - 0x2800;
+    let last = |line: &str| line.chars().last().unwrap() as u32 - 0x2800;
+    assert_ne!(first(lines[14]) & 0x40, 0);
+    assert_ne!(last(lines[2]) & 0x08, 0);
+}
+
+#[test]
+fn colors_match_the_series_index() {
+    let s = plotter()
+        .render_terminal(String::new(), [40, 20], true)
+        .unwrap();
+    assert!(s.contains("\x1b[34m──\x1b[0m a"));
+    assert!(s.contains("\x1b[31m••\x1b[0m b"));
+
+    let plain = plotter()
+        .render_terminal(String::new(), [40, 20], false)
+        .unwrap();
+    assert!(!plain.contains('\x1b'));
+}
+
+#[test]
+fn histogram_bars_are_filled() {
+    let mut plotter = poloto::plot("", "", "");
+    plotter.histogram("", [[0.0, 10.0], [10.0, 10.0]].iter().twice_iter());
+    let s = plotter
+        .render_terminal(String::new(), [40, 20], false)
+        .unwrap();
+    assert!(s.contains("⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿"));
+}
+
+#[test]
+fn too_small() {
+    let res = plotter().render_terminal(String::new(), [10, 6], false);
+    assert!(matches!(res, Err(poloto::Error::InvalidData(_))));
+}
+
+#[test]
+fn narrow_and_short_sizes_render() {
+    //Few ticks on these ranges can have a step bigger than the range.
+    let data = [
+        [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]],
+        [[5.5, 0.1], [7.0, 0.3], [9.5, 0.2]],
+    ];
+    for data in data.iter() {
+        let mut plotter = poloto::plot("title", "x", "y");
+        plotter.line("data", data.iter().twice_iter());
+        for columns in 15..=80 {
+            for rows in 7..=24 {
+                let s = plotter
+                    .render_terminal(String::new(), [columns, rows], false)
+                    .unwrap();
+                assert_eq!(s.lines().count(), rows);
+                //Every axis has at least one tick.
+                assert!(s.contains('┤') && s.contains('┬'), "{:?}", [columns, rows]);
+            }
+        }
+    }
+}
+
+#[test]
+fn inverted_axes_are_labelled() {
+    let mut plotter = plotter();
+    plotter.invert_x().invert_y();
+    let s = plotter
+        .render_terminal(String::new(), [40, 20], false)
+        .unwrap();
+    let lines: Vec<_> = s.lines().collect();
+    //The y axis goes from 0 at the top to 10 at the bottom.
+    assert!(lines[2].starts_with(" 0 ┤"));
+    assert!(lines[14].starts_with("10 ┤"));
+    //Every x tick is labelled, from 10 on the left to 0 on the right.
+    let labels: Vec<_> = lines[16].split_whitespace().collect();
+    assert_eq!(labels.len(), lines[15].matches('┬').count());
+    assert_eq!(labels.first(), Some(&"10"));
+    assert_eq!(labels.last(), Some(&"0"));
+}
@@ -0,0 +1,73 @@
+use poloto::prelude::*;
+
+fn titles(s: &str) -> Vec<&str> {
+    s.split("<title>")
+        .skip(1)
+        .map(|t| &t[..t.find("</title>").unwrap()])
+        .collect()
+}
+
+#[test]
+fn no_tooltips_by_default() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.scatter("data", [[0.0, 0.0], [10.0, 10.0]].iter().twice_iter());
+    let s = plotter.render_to_string().unwrap();
+    assert!(titles(&s).is_empty());
+}
+
+#[test]
+fn scatter_circles_have_titles() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter
+        .scatter("data", [[0.0, 0.5], [10.0, 10.0]].iter().twice_iter())
+        .tooltips();
+    let s = plotter.render_to_string().unwrap();
+
+    assert_eq!(titles(&s), ["0, 0.5", "10, 10"]);
+    assert!(s.contains("r=\"5\"><title>0, 0.5</title></circle>"));
+}
+
+#[test]
+fn histogram_bars_have_titles() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter
+        .histogram("data", [[0, 3], [1, 4], [2, 0]].iter().twice_iter())
+        .tooltips_with(|[x, y]| format!("bin {} has {}", x, y));
+    let s = plotter.render_to_string().unwrap();
+
+    //There is no bar for the last point.
+    assert_eq!(titles(&s), ["bin 0 has 3", "bin 1 has 4"]);
+    assert_eq!(s.matches("<rect").count(), titles(&s).len() + 3);
+}
+
+#[test]
+fn lines_have_invisible_targets_on_every_point() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter.decimation(poloto::Decimation::Lttb(3));
+    plotter
+        .line(
+            "data",
+            (0..10)
+                .map(|x| [x as f64, if x == 5 { f64::NAN } else { x as f64 }])
+                .twice_iter(),
+        )
+        .tooltips();
+    let s = plotter.render_to_string().unwrap();
+
+    //Every finite point gets a target, even if the line is decimated.
+    let t = titles(&s);
+    assert_eq!(t.len(), 9);
+    assert_eq!(t[0], "0, 0");
+    assert_eq!(t[8], "9, 9");
+    assert!(s.contains("<g fill=\"black\" opacity=\"0\"><circle cx=\"150\" cy=\"400\""));
+}
+
+#[test]
+fn big_values_use_scientific_notation() {
+    let mut plotter = poloto::plot("title", "x", "y");
+    plotter
+        .scatter("data", [[0.000_001, 123_456_789.0]].iter().twice_iter())
+        .tooltips();
+    let s = plotter.render_to_string().unwrap();
+    assert_eq!(titles(&s), ["1e-6, 1.23456789e8"]);
+}